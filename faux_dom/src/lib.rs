@@ -1,92 +1,1724 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    sync::{Arc, Mutex},
+};
+
 use bevy::{
-    a11y::accesskit::Node,
-    asset::Handle,
-    ecs::{entity::Entity, system::Commands},
-    hierarchy::BuildChildren,
-    text::{Font, Text, TextStyle},
-    ui::node_bundles::{NodeBundle, TextBundle},
+    app::{App, Plugin, Update},
+    asset::{AssetApp, AssetPlugin, Handle},
+    core::Name,
+    ecs::{
+        bundle::Bundle,
+        component::Component,
+        entity::Entity,
+        event::EventReader,
+        system::{Commands, Query, Resource},
+        world::{EntityWorldMut, World},
+    },
+    hierarchy::{
+        despawn_with_children_recursive, BuildChildren, BuildWorldChildren, ChildBuilder, Children,
+        DespawnRecursiveExt, HierarchyPlugin, Parent, WorldChildBuilder,
+    },
+    input::{
+        mouse::{MouseScrollUnit, MouseWheel},
+        InputPlugin,
+    },
+    log::warn,
+    math::Vec2,
+    render::{color::Color, render_resource::Shader, texture::ImagePlugin},
+    sprite::TextureAtlas,
+    text::{BreakLineOn, Font, Text, TextAlignment, TextPlugin, TextStyle},
+    transform::TransformPlugin,
+    ui::{
+        node_bundles::{NodeBundle, TextBundle},
+        Node, Outline, OverflowAxis, RelativeCursorPosition, Style, UiPlugin, UiRect, Val,
+    },
+    window::WindowPlugin,
+    MinimalPlugins,
 };
-pub use faux_dom_shared::FauxNode;
-pub use faux_dom_shared::Properties;
+pub use faux_dom_shared::Error;
+
+/// The attributes of an element, as written in `rsx!`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Properties {
+    /// Registers the element in the [`FauxIds`] of the [`RenderedTree`], and names its entity.
+    pub id: Option<String>,
+    /// Inserted as the entity's [`Name`], taking the place of the one derived from `id`.
+    pub name: Option<String>,
+    /// Filled with the element's entity as soon as it is spawned.
+    pub reference: Option<FauxRef>,
+    /// Inserted on the element's entity after its `NodeBundle`.
+    pub components: Option<FauxComponents>,
+    pub class: Option<String>,
+    /// Merged on top of the styles resolved from `class`. Only the fields a [`FauxStyle`] sets,
+    /// or that a `Style` changes from its default, override the classes; setting a field back to
+    /// its default takes an explicit [`FauxStyle`].
+    pub style: Option<Box<FauxStyle>>,
+    /// `data-*` attributes, keyed without the `data-` prefix, inserted as [`FauxData`].
+    pub data: HashMap<String, String>,
+}
+
+/// A tree built by `rsx!`, ready to be rendered. Trees can't be `Eq` or `Hash`, since styles
+/// hold floats.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FauxNode {
+    Text(String),
+    Div(Vec<FauxNode>, Properties),
+    Fragment(Vec<FauxNode>),
+}
+
+impl FauxNode {
+    /// Compares two trees like `==`, except that text only has to match up to whitespace, so
+    /// `"a  b"` and `" a\nb "` are the same.
+    pub fn structurally_eq(&self, other: &FauxNode) -> bool {
+        fn children_eq(a: &[FauxNode], b: &[FauxNode]) -> bool {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.structurally_eq(b))
+        }
+
+        match (self, other) {
+            (FauxNode::Text(a), FauxNode::Text(b)) => a.split_whitespace().eq(b.split_whitespace()),
+            (FauxNode::Div(a, a_properties), FauxNode::Div(b, b_properties)) => {
+                a_properties == b_properties && children_eq(a, b)
+            }
+            (FauxNode::Fragment(a), FauxNode::Fragment(b)) => children_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Writes the tree one node per line, indented by depth, with the inline style of each element
+/// listed a field per line under it.
+impl fmt::Display for FauxNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_node(f, self, 0, None)
+    }
+}
+
+/// Writes `node` like its [`Display`](fmt::Display) impl, but with the styles each element
+/// resolves to through `stylesheet`, along with any warnings, in place of just its inline style.
+/// Only the layout fields that differ from `Style::default()` are listed, so the output is
+/// stable enough to be kept as a golden file.
+pub fn snapshot(node: &FauxNode, stylesheet: &impl Stylesheet) -> String {
+    let mut snapshot = String::new();
+    write_node(&mut snapshot, node, 0, Some(stylesheet)).expect("writing to a string doesn't fail");
+    snapshot
+}
+
+fn write_node(
+    out: &mut impl fmt::Write,
+    node: &FauxNode,
+    depth: usize,
+    stylesheet: Option<&dyn Stylesheet>,
+) -> fmt::Result {
+    let indent = "  ".repeat(depth);
+    match node {
+        FauxNode::Text(text) => writeln!(out, "{indent}{text:?}"),
+        // Fragments are flattened when rendering, so their children are written as siblings.
+        FauxNode::Fragment(children) => {
+            for child in children {
+                write_node(out, child, depth, stylesheet)?;
+            }
+            Ok(())
+        }
+        FauxNode::Div(children, properties) => {
+            write!(out, "{indent}div")?;
+            if let Some(id) = &properties.id {
+                write!(out, " id={id:?}")?;
+            }
+            if let Some(name) = &properties.name {
+                write!(out, " name={name:?}")?;
+            }
+            if let Some(class) = &properties.class {
+                write!(out, " class={class:?}")?;
+            }
+            let mut data: Vec<_> = properties.data.iter().collect();
+            data.sort();
+            for (key, value) in data {
+                write!(out, " data-{key}={value:?}")?;
+            }
+            if properties.reference.is_some() {
+                write!(out, " ref")?;
+            }
+            if properties.components.is_some() {
+                write!(out, " components")?;
+            }
+            writeln!(out)?;
+
+            let mut style = FauxStyle::default();
+            if let Some(stylesheet) = stylesheet {
+                let names = properties.class.as_deref().unwrap_or_default();
+                match stylesheet.get_styles(names) {
+                    Ok(styles) => {
+                        for warning in &styles.warnings {
+                            writeln!(out, "{indent}  warning: {warning}")?;
+                        }
+                        style = styles.style;
+                    }
+                    Err(error) => writeln!(out, "{indent}  error: {error}")?,
+                }
+            }
+            if let Some(inline) = &properties.style {
+                style.merge(FauxStyle::clone(inline));
+            }
+            if stylesheet.is_some() {
+                style = style.resolved();
+            }
+            for (name, value) in style.set_fields() {
+                writeln!(out, "{indent}  {name}: {value:?}")?;
+            }
+
+            for child in children {
+                write_node(out, child, depth + 1, stylesheet)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Styles for a single node, resolved from its classes or given inline. Every field is optional
+/// so that styles from several sources can be layered with [`FauxStyle::merge`], and only the
+/// fields that were actually set are written to the node.
+#[derive(Clone, Default)]
+pub struct FauxStyle {
+    pub display: Option<bevy::ui::Display>,
+    pub position_type: Option<bevy::ui::PositionType>,
+    pub overflow_x: Option<OverflowAxis>,
+    pub overflow_y: Option<OverflowAxis>,
+    pub direction: Option<bevy::ui::Direction>,
+    pub left: Option<Val>,
+    pub right: Option<Val>,
+    pub top: Option<Val>,
+    pub bottom: Option<Val>,
+    pub width: Option<Val>,
+    pub height: Option<Val>,
+    pub min_width: Option<Val>,
+    pub min_height: Option<Val>,
+    pub max_width: Option<Val>,
+    pub max_height: Option<Val>,
+    /// `Some(None)` clears an aspect ratio set by a layer underneath.
+    pub aspect_ratio: Option<Option<f32>>,
+    pub align_items: Option<bevy::ui::AlignItems>,
+    pub justify_items: Option<bevy::ui::JustifyItems>,
+    pub align_self: Option<bevy::ui::AlignSelf>,
+    pub justify_self: Option<bevy::ui::JustifySelf>,
+    pub align_content: Option<bevy::ui::AlignContent>,
+    pub justify_content: Option<bevy::ui::JustifyContent>,
+    pub margin: FauxRect,
+    pub padding: FauxRect,
+    pub border: FauxRect,
+    pub flex_direction: Option<bevy::ui::FlexDirection>,
+    pub flex_wrap: Option<bevy::ui::FlexWrap>,
+    pub flex_grow: Option<f32>,
+    pub flex_shrink: Option<f32>,
+    pub flex_basis: Option<Val>,
+    pub row_gap: Option<Val>,
+    pub column_gap: Option<Val>,
+    pub grid_auto_flow: Option<bevy::ui::GridAutoFlow>,
+    pub grid_template_rows: Option<Vec<bevy::ui::RepeatedGridTrack>>,
+    pub grid_template_columns: Option<Vec<bevy::ui::RepeatedGridTrack>>,
+    pub grid_auto_rows: Option<Vec<bevy::ui::GridTrack>>,
+    pub grid_auto_columns: Option<Vec<bevy::ui::GridTrack>>,
+    pub grid_row: Option<bevy::ui::GridPlacement>,
+    pub grid_column: Option<bevy::ui::GridPlacement>,
+    pub background_color: Option<bevy::ui::BackgroundColor>,
+    pub border_color: Option<bevy::ui::BorderColor>,
+    /// An outline is drawn once its width is set, in the text color unless `outline_color` is.
+    pub outline_width: Option<Val>,
+    pub outline_offset: Option<Val>,
+    pub outline_color: Option<Color>,
+    pub visibility: Option<bevy::render::view::Visibility>,
+    /// Multiplies the alpha of this node's colors and those of all its descendants.
+    pub opacity: Option<f32>,
+    pub z_index: Option<bevy::ui::ZIndex>,
+    pub font_size: Option<f32>,
+    pub color: Option<bevy::render::color::Color>,
+    /// Clip the node vertically and let the mouse wheel scroll its children.
+    pub scroll_y: Option<bool>,
+    pub text_alignment: Option<TextAlignment>,
+    pub linebreak_behavior: Option<BreakLineOn>,
+    pub font_family: Option<String>,
+    pub font_weight: Option<FontWeight>,
+    pub italic: Option<bool>,
+}
+
+impl FauxStyle {
+    /// Layers `other` on top of `self`: every field `other` sets wins, the rest are kept.
+    pub fn merge(&mut self, other: FauxStyle) {
+        let FauxStyle {
+            display,
+            position_type,
+            overflow_x,
+            overflow_y,
+            direction,
+            left,
+            right,
+            top,
+            bottom,
+            width,
+            height,
+            min_width,
+            min_height,
+            max_width,
+            max_height,
+            aspect_ratio,
+            align_items,
+            justify_items,
+            align_self,
+            justify_self,
+            align_content,
+            justify_content,
+            margin,
+            padding,
+            border,
+            flex_direction,
+            flex_wrap,
+            flex_grow,
+            flex_shrink,
+            flex_basis,
+            row_gap,
+            column_gap,
+            grid_auto_flow,
+            grid_template_rows,
+            grid_template_columns,
+            grid_auto_rows,
+            grid_auto_columns,
+            grid_row,
+            grid_column,
+            background_color,
+            border_color,
+            outline_width,
+            outline_offset,
+            outline_color,
+            visibility,
+            opacity,
+            z_index,
+            font_size,
+            color,
+            scroll_y,
+            text_alignment,
+            linebreak_behavior,
+            font_family,
+            font_weight,
+            italic,
+        } = other;
+
+        merge(&mut self.display, display);
+        merge(&mut self.position_type, position_type);
+        merge(&mut self.overflow_x, overflow_x);
+        merge(&mut self.overflow_y, overflow_y);
+        merge(&mut self.direction, direction);
+        merge(&mut self.left, left);
+        merge(&mut self.right, right);
+        merge(&mut self.top, top);
+        merge(&mut self.bottom, bottom);
+        merge(&mut self.width, width);
+        merge(&mut self.height, height);
+        merge(&mut self.min_width, min_width);
+        merge(&mut self.min_height, min_height);
+        merge(&mut self.max_width, max_width);
+        merge(&mut self.max_height, max_height);
+        merge(&mut self.aspect_ratio, aspect_ratio);
+        merge(&mut self.align_items, align_items);
+        merge(&mut self.justify_items, justify_items);
+        merge(&mut self.align_self, align_self);
+        merge(&mut self.justify_self, justify_self);
+        merge(&mut self.align_content, align_content);
+        merge(&mut self.justify_content, justify_content);
+        self.margin.merge(margin);
+        self.padding.merge(padding);
+        self.border.merge(border);
+        merge(&mut self.flex_direction, flex_direction);
+        merge(&mut self.flex_wrap, flex_wrap);
+        merge(&mut self.flex_grow, flex_grow);
+        merge(&mut self.flex_shrink, flex_shrink);
+        merge(&mut self.flex_basis, flex_basis);
+        merge(&mut self.row_gap, row_gap);
+        merge(&mut self.column_gap, column_gap);
+        merge(&mut self.grid_auto_flow, grid_auto_flow);
+        merge(&mut self.grid_template_rows, grid_template_rows);
+        merge(&mut self.grid_template_columns, grid_template_columns);
+        merge(&mut self.grid_auto_rows, grid_auto_rows);
+        merge(&mut self.grid_auto_columns, grid_auto_columns);
+        merge(&mut self.grid_row, grid_row);
+        merge(&mut self.grid_column, grid_column);
+        merge(&mut self.background_color, background_color);
+        merge(&mut self.border_color, border_color);
+        merge(&mut self.outline_width, outline_width);
+        merge(&mut self.outline_offset, outline_offset);
+        merge(&mut self.outline_color, outline_color);
+        merge(&mut self.visibility, visibility);
+        merge(&mut self.opacity, opacity);
+        merge(&mut self.z_index, z_index);
+        merge(&mut self.font_size, font_size);
+        merge(&mut self.color, color);
+        merge(&mut self.scroll_y, scroll_y);
+        merge(&mut self.text_alignment, text_alignment);
+        merge(&mut self.linebreak_behavior, linebreak_behavior);
+        merge(&mut self.font_family, font_family);
+        merge(&mut self.font_weight, font_weight);
+        merge(&mut self.italic, italic);
+    }
+
+    /// Writes the layout fields that were set onto `style`, leaving the others untouched.
+    pub fn apply_to(&self, style: &mut Style) {
+        apply(&mut style.display, &self.display);
+        apply(&mut style.position_type, &self.position_type);
+        apply(&mut style.overflow.x, &self.overflow_x);
+        apply(&mut style.overflow.y, &self.overflow_y);
+        apply(&mut style.direction, &self.direction);
+        apply(&mut style.left, &self.left);
+        apply(&mut style.right, &self.right);
+        apply(&mut style.top, &self.top);
+        apply(&mut style.bottom, &self.bottom);
+        apply(&mut style.width, &self.width);
+        apply(&mut style.height, &self.height);
+        apply(&mut style.min_width, &self.min_width);
+        apply(&mut style.min_height, &self.min_height);
+        apply(&mut style.max_width, &self.max_width);
+        apply(&mut style.max_height, &self.max_height);
+        apply(&mut style.aspect_ratio, &self.aspect_ratio);
+        apply(&mut style.align_items, &self.align_items);
+        apply(&mut style.justify_items, &self.justify_items);
+        apply(&mut style.align_self, &self.align_self);
+        apply(&mut style.justify_self, &self.justify_self);
+        apply(&mut style.align_content, &self.align_content);
+        apply(&mut style.justify_content, &self.justify_content);
+        self.margin.apply_to(&mut style.margin);
+        self.padding.apply_to(&mut style.padding);
+        self.border.apply_to(&mut style.border);
+        apply(&mut style.flex_direction, &self.flex_direction);
+        apply(&mut style.flex_wrap, &self.flex_wrap);
+        apply(&mut style.flex_grow, &self.flex_grow);
+        apply(&mut style.flex_shrink, &self.flex_shrink);
+        apply(&mut style.flex_basis, &self.flex_basis);
+        apply(&mut style.row_gap, &self.row_gap);
+        apply(&mut style.column_gap, &self.column_gap);
+        apply(&mut style.grid_auto_flow, &self.grid_auto_flow);
+        apply(&mut style.grid_template_rows, &self.grid_template_rows);
+        apply(
+            &mut style.grid_template_columns,
+            &self.grid_template_columns,
+        );
+        apply(&mut style.grid_auto_rows, &self.grid_auto_rows);
+        apply(&mut style.grid_auto_columns, &self.grid_auto_columns);
+        apply(&mut style.grid_row, &self.grid_row);
+        apply(&mut style.grid_column, &self.grid_column);
+    }
+}
+
+/// Takes only the fields of `style` that differ from `Style::default()`, so that
+/// `Style { width: Val::Percent(50.0), ..default() }` sets the width and nothing else.
+impl From<Style> for FauxStyle {
+    fn from(style: Style) -> Self {
+        let default = Style::default();
+        let Style {
+            display,
+            position_type,
+            overflow,
+            direction,
+            left,
+            right,
+            top,
+            bottom,
+            width,
+            height,
+            min_width,
+            min_height,
+            max_width,
+            max_height,
+            aspect_ratio,
+            align_items,
+            justify_items,
+            align_self,
+            justify_self,
+            align_content,
+            justify_content,
+            margin,
+            padding,
+            border,
+            flex_direction,
+            flex_wrap,
+            flex_grow,
+            flex_shrink,
+            flex_basis,
+            row_gap,
+            column_gap,
+            grid_auto_flow,
+            grid_template_rows,
+            grid_template_columns,
+            grid_auto_rows,
+            grid_auto_columns,
+            grid_row,
+            grid_column,
+        } = style;
+
+        FauxStyle {
+            display: changed(display, default.display),
+            position_type: changed(position_type, default.position_type),
+            overflow_x: changed(overflow.x, default.overflow.x),
+            overflow_y: changed(overflow.y, default.overflow.y),
+            direction: changed(direction, default.direction),
+            left: changed(left, default.left),
+            right: changed(right, default.right),
+            top: changed(top, default.top),
+            bottom: changed(bottom, default.bottom),
+            width: changed(width, default.width),
+            height: changed(height, default.height),
+            min_width: changed(min_width, default.min_width),
+            min_height: changed(min_height, default.min_height),
+            max_width: changed(max_width, default.max_width),
+            max_height: changed(max_height, default.max_height),
+            aspect_ratio: changed(aspect_ratio, default.aspect_ratio),
+            align_items: changed(align_items, default.align_items),
+            justify_items: changed(justify_items, default.justify_items),
+            align_self: changed(align_self, default.align_self),
+            justify_self: changed(justify_self, default.justify_self),
+            align_content: changed(align_content, default.align_content),
+            justify_content: changed(justify_content, default.justify_content),
+            margin: FauxRect::changed(margin, default.margin),
+            padding: FauxRect::changed(padding, default.padding),
+            border: FauxRect::changed(border, default.border),
+            flex_direction: changed(flex_direction, default.flex_direction),
+            flex_wrap: changed(flex_wrap, default.flex_wrap),
+            flex_grow: changed(flex_grow, default.flex_grow),
+            flex_shrink: changed(flex_shrink, default.flex_shrink),
+            flex_basis: changed(flex_basis, default.flex_basis),
+            row_gap: changed(row_gap, default.row_gap),
+            column_gap: changed(column_gap, default.column_gap),
+            grid_auto_flow: changed(grid_auto_flow, default.grid_auto_flow),
+            grid_template_rows: changed(grid_template_rows, default.grid_template_rows),
+            grid_template_columns: changed(grid_template_columns, default.grid_template_columns),
+            grid_auto_rows: changed(grid_auto_rows, default.grid_auto_rows),
+            grid_auto_columns: changed(grid_auto_columns, default.grid_auto_columns),
+            grid_row: changed(grid_row, default.grid_row),
+            grid_column: changed(grid_column, default.grid_column),
+            ..Default::default()
+        }
+    }
+}
+
+/// The sides of a margin, padding or border, each of which may be left unset.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct FauxRect {
+    pub left: Option<Val>,
+    pub right: Option<Val>,
+    pub top: Option<Val>,
+    pub bottom: Option<Val>,
+}
+
+impl FauxRect {
+    pub fn all(val: Val) -> Self {
+        Self {
+            left: Some(val),
+            right: Some(val),
+            top: Some(val),
+            bottom: Some(val),
+        }
+    }
+
+    fn changed(rect: UiRect, default: UiRect) -> Self {
+        Self {
+            left: changed(rect.left, default.left),
+            right: changed(rect.right, default.right),
+            top: changed(rect.top, default.top),
+            bottom: changed(rect.bottom, default.bottom),
+        }
+    }
+
+    pub fn merge(&mut self, other: FauxRect) {
+        merge(&mut self.left, other.left);
+        merge(&mut self.right, other.right);
+        merge(&mut self.top, other.top);
+        merge(&mut self.bottom, other.bottom);
+    }
+
+    pub fn apply_to(&self, rect: &mut UiRect) {
+        apply(&mut rect.left, &self.left);
+        apply(&mut rect.right, &self.right);
+        apply(&mut rect.top, &self.top);
+        apply(&mut rect.bottom, &self.bottom);
+    }
+}
+
+fn merge<T>(field: &mut Option<T>, other: Option<T>) {
+    if other.is_some() {
+        *field = other;
+    }
+}
+
+fn changed<T: PartialEq>(value: T, default: T) -> Option<T> {
+    (value != default).then_some(value)
+}
+
+fn apply<T: Clone>(field: &mut T, value: &Option<T>) {
+    if let Some(value) = value {
+        *field = value.clone();
+    }
+}
+
+impl FauxStyle {
+    /// The fields that were set, by name, in declaration order.
+    fn set_fields(&self) -> Vec<(&'static str, &dyn fmt::Debug)> {
+        let FauxStyle {
+            display,
+            position_type,
+            overflow_x,
+            overflow_y,
+            direction,
+            left,
+            right,
+            top,
+            bottom,
+            width,
+            height,
+            min_width,
+            min_height,
+            max_width,
+            max_height,
+            aspect_ratio,
+            align_items,
+            justify_items,
+            align_self,
+            justify_self,
+            align_content,
+            justify_content,
+            margin,
+            padding,
+            border,
+            flex_direction,
+            flex_wrap,
+            flex_grow,
+            flex_shrink,
+            flex_basis,
+            row_gap,
+            column_gap,
+            grid_auto_flow,
+            grid_template_rows,
+            grid_template_columns,
+            grid_auto_rows,
+            grid_auto_columns,
+            grid_row,
+            grid_column,
+            background_color,
+            border_color,
+            outline_width,
+            outline_offset,
+            outline_color,
+            visibility,
+            opacity,
+            z_index,
+            font_size,
+            color,
+            scroll_y,
+            text_alignment,
+            linebreak_behavior,
+            font_family,
+            font_weight,
+            italic,
+        } = self;
+
+        let mut fields = Vec::new();
+        set_field(&mut fields, "display", display);
+        set_field(&mut fields, "position_type", position_type);
+        set_field(&mut fields, "overflow_x", overflow_x);
+        set_field(&mut fields, "overflow_y", overflow_y);
+        set_field(&mut fields, "direction", direction);
+        set_field(&mut fields, "left", left);
+        set_field(&mut fields, "right", right);
+        set_field(&mut fields, "top", top);
+        set_field(&mut fields, "bottom", bottom);
+        set_field(&mut fields, "width", width);
+        set_field(&mut fields, "height", height);
+        set_field(&mut fields, "min_width", min_width);
+        set_field(&mut fields, "min_height", min_height);
+        set_field(&mut fields, "max_width", max_width);
+        set_field(&mut fields, "max_height", max_height);
+        set_field(&mut fields, "aspect_ratio", aspect_ratio);
+        set_field(&mut fields, "align_items", align_items);
+        set_field(&mut fields, "justify_items", justify_items);
+        set_field(&mut fields, "align_self", align_self);
+        set_field(&mut fields, "justify_self", justify_self);
+        set_field(&mut fields, "align_content", align_content);
+        set_field(&mut fields, "justify_content", justify_content);
+        margin.set_fields("margin", &mut fields);
+        padding.set_fields("padding", &mut fields);
+        border.set_fields("border", &mut fields);
+        set_field(&mut fields, "flex_direction", flex_direction);
+        set_field(&mut fields, "flex_wrap", flex_wrap);
+        set_field(&mut fields, "flex_grow", flex_grow);
+        set_field(&mut fields, "flex_shrink", flex_shrink);
+        set_field(&mut fields, "flex_basis", flex_basis);
+        set_field(&mut fields, "row_gap", row_gap);
+        set_field(&mut fields, "column_gap", column_gap);
+        set_field(&mut fields, "grid_auto_flow", grid_auto_flow);
+        set_field(&mut fields, "grid_template_rows", grid_template_rows);
+        set_field(&mut fields, "grid_template_columns", grid_template_columns);
+        set_field(&mut fields, "grid_auto_rows", grid_auto_rows);
+        set_field(&mut fields, "grid_auto_columns", grid_auto_columns);
+        set_field(&mut fields, "grid_row", grid_row);
+        set_field(&mut fields, "grid_column", grid_column);
+        set_field(&mut fields, "background_color", background_color);
+        set_field(&mut fields, "border_color", border_color);
+        set_field(&mut fields, "outline_width", outline_width);
+        set_field(&mut fields, "outline_offset", outline_offset);
+        set_field(&mut fields, "outline_color", outline_color);
+        set_field(&mut fields, "visibility", visibility);
+        set_field(&mut fields, "opacity", opacity);
+        set_field(&mut fields, "z_index", z_index);
+        set_field(&mut fields, "font_size", font_size);
+        set_field(&mut fields, "color", color);
+        set_field(&mut fields, "scroll_y", scroll_y);
+        set_field(&mut fields, "text_alignment", text_alignment);
+        set_field(&mut fields, "linebreak_behavior", linebreak_behavior);
+        set_field(&mut fields, "font_family", font_family);
+        set_field(&mut fields, "font_weight", font_weight);
+        set_field(&mut fields, "italic", italic);
+        fields
+    }
+
+    /// What the node ends up with once the style is written: layout fields that match
+    /// `Style::default()` are dropped, since setting them changes nothing.
+    fn resolved(&self) -> FauxStyle {
+        let mut layout = Style::default();
+        self.apply_to(&mut layout);
+
+        FauxStyle {
+            background_color: self.background_color,
+            border_color: self.border_color,
+            outline_width: self.outline_width,
+            outline_offset: self.outline_offset,
+            outline_color: self.outline_color,
+            visibility: self.visibility,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            font_size: self.font_size,
+            color: self.color,
+            scroll_y: self.scroll_y,
+            text_alignment: self.text_alignment,
+            linebreak_behavior: self.linebreak_behavior,
+            font_family: self.font_family.clone(),
+            font_weight: self.font_weight,
+            italic: self.italic,
+            ..FauxStyle::from(layout)
+        }
+    }
+}
+
+impl FauxRect {
+    fn set_fields<'a>(
+        &'a self,
+        name: &'static str,
+        fields: &mut Vec<(&'static str, &'a dyn fmt::Debug)>,
+    ) {
+        if self.left.is_some()
+            || self.right.is_some()
+            || self.top.is_some()
+            || self.bottom.is_some()
+        {
+            fields.push((name, self));
+        }
+    }
+}
+
+fn set_field<'a, T: fmt::Debug>(
+    fields: &mut Vec<(&'static str, &'a dyn fmt::Debug)>,
+    name: &'static str,
+    value: &'a Option<T>,
+) {
+    if let Some(value) = value {
+        fields.push((name, value));
+    }
+}
+
+/// Written out because `BackgroundColor`, `BorderColor` and `ZIndex` don't implement
+/// `PartialEq`.
+impl PartialEq for FauxStyle {
+    fn eq(&self, other: &Self) -> bool {
+        let FauxStyle {
+            display,
+            position_type,
+            overflow_x,
+            overflow_y,
+            direction,
+            left,
+            right,
+            top,
+            bottom,
+            width,
+            height,
+            min_width,
+            min_height,
+            max_width,
+            max_height,
+            aspect_ratio,
+            align_items,
+            justify_items,
+            align_self,
+            justify_self,
+            align_content,
+            justify_content,
+            margin,
+            padding,
+            border,
+            flex_direction,
+            flex_wrap,
+            flex_grow,
+            flex_shrink,
+            flex_basis,
+            row_gap,
+            column_gap,
+            grid_auto_flow,
+            grid_template_rows,
+            grid_template_columns,
+            grid_auto_rows,
+            grid_auto_columns,
+            grid_row,
+            grid_column,
+            background_color,
+            border_color,
+            outline_width,
+            outline_offset,
+            outline_color,
+            visibility,
+            opacity,
+            z_index,
+            font_size,
+            color,
+            scroll_y,
+            text_alignment,
+            linebreak_behavior,
+            font_family,
+            font_weight,
+            italic,
+        } = self;
+
+        *display == other.display
+            && *position_type == other.position_type
+            && *overflow_x == other.overflow_x
+            && *overflow_y == other.overflow_y
+            && *direction == other.direction
+            && *left == other.left
+            && *right == other.right
+            && *top == other.top
+            && *bottom == other.bottom
+            && *width == other.width
+            && *height == other.height
+            && *min_width == other.min_width
+            && *min_height == other.min_height
+            && *max_width == other.max_width
+            && *max_height == other.max_height
+            && *aspect_ratio == other.aspect_ratio
+            && *align_items == other.align_items
+            && *justify_items == other.justify_items
+            && *align_self == other.align_self
+            && *justify_self == other.justify_self
+            && *align_content == other.align_content
+            && *justify_content == other.justify_content
+            && *margin == other.margin
+            && *padding == other.padding
+            && *border == other.border
+            && *flex_direction == other.flex_direction
+            && *flex_wrap == other.flex_wrap
+            && *flex_grow == other.flex_grow
+            && *flex_shrink == other.flex_shrink
+            && *flex_basis == other.flex_basis
+            && *row_gap == other.row_gap
+            && *column_gap == other.column_gap
+            && *grid_auto_flow == other.grid_auto_flow
+            && *grid_template_rows == other.grid_template_rows
+            && *grid_template_columns == other.grid_template_columns
+            && *grid_auto_rows == other.grid_auto_rows
+            && *grid_auto_columns == other.grid_auto_columns
+            && *grid_row == other.grid_row
+            && *grid_column == other.grid_column
+            && background_color.map(|color| color.0) == other.background_color.map(|color| color.0)
+            && border_color.map(|color| color.0) == other.border_color.map(|color| color.0)
+            && *outline_width == other.outline_width
+            && *outline_offset == other.outline_offset
+            && *outline_color == other.outline_color
+            && *visibility == other.visibility
+            && *opacity == other.opacity
+            && z_index.map(z_index_key) == other.z_index.map(z_index_key)
+            && *font_size == other.font_size
+            && *color == other.color
+            && *scroll_y == other.scroll_y
+            && *text_alignment == other.text_alignment
+            && *linebreak_behavior == other.linebreak_behavior
+            && *font_family == other.font_family
+            && *font_weight == other.font_weight
+            && *italic == other.italic
+    }
+}
+
+fn z_index_key(z_index: bevy::ui::ZIndex) -> (bool, i32) {
+    match z_index {
+        bevy::ui::ZIndex::Local(z) => (false, z),
+        bevy::ui::ZIndex::Global(z) => (true, z),
+    }
+}
+
+/// Lists only the fields that were set.
+impl fmt::Debug for FauxStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("FauxStyle");
+        for (name, value) in self.set_fields() {
+            debug.field(name, value);
+        }
+        debug.finish()
+    }
+}
+
+/// Lists only the sides that were set.
+impl fmt::Debug for FauxRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fields = Vec::new();
+        set_field(&mut fields, "left", &self.left);
+        set_field(&mut fields, "right", &self.right);
+        set_field(&mut fields, "top", &self.top);
+        set_field(&mut fields, "bottom", &self.bottom);
+
+        let mut debug = f.debug_struct("FauxRect");
+        for (name, value) in fields {
+            debug.field(name, value);
+        }
+        debug.finish()
+    }
+}
+
+#[derive(Clone)]
+struct CascadingStyle {
+    font_family: Option<String>,
+    font_weight: Option<FontWeight>,
+    italic: Option<bool>,
+    font_size: Option<f32>,
+    color: Option<bevy::render::color::Color>,
+    text_alignment: Option<TextAlignment>,
+    linebreak_behavior: Option<BreakLineOn>,
+    opacity: f32,
+}
+
+impl CascadingStyle {
+    fn font_face(&self) -> FontFace {
+        FontFace {
+            family: self
+                .font_family
+                .clone()
+                .unwrap_or_else(|| FontFace::DEFAULT_FAMILY.to_string()),
+            weight: self.font_weight.unwrap_or_default(),
+            italic: self.italic.unwrap_or(false),
+        }
+    }
+}
+
+/// The styles resolved from a class attribute, along with the classes the stylesheet skipped over.
+#[derive(Default)]
+pub struct Styles {
+    pub style: FauxStyle,
+    pub warnings: Vec<Error>,
+}
+
+impl Styles {
+    /// Layers `other` on top, keeping the warnings of both.
+    fn layer(&mut self, other: Styles) {
+        self.style.merge(other.style);
+        self.warnings.extend(other.warnings);
+    }
+}
+
+pub trait Stylesheet {
+    fn get_styles(&self, names: &str) -> Result<Styles, Error>;
+}
+
+/// Wraps a stylesheet and turns its first warning into an error, so tests fail on a misspelled
+/// class.
+pub struct Strict<S>(pub S);
+
+impl<S: Stylesheet> Stylesheet for Strict<S> {
+    fn get_styles(&self, names: &str) -> Result<Styles, Error> {
+        let mut styles = self.0.get_styles(names)?;
+        if styles.warnings.is_empty() {
+            Ok(styles)
+        } else {
+            Err(styles.warnings.swap_remove(0))
+        }
+    }
+}
+
+/// Expands project-specific classes like `btn-primary` into utilities, in the spirit of Tailwind's
+/// `@apply`, and hands everything else to the stylesheet underneath. Aliases may refer to other
+/// aliases, including those of a wrapped `Aliases`, and classes written after an alias override
+/// its utilities, so `btn px-8` widens a button without a conflict warning.
+pub struct Aliases<S> {
+    stylesheet: S,
+    aliases: HashMap<String, String>,
+}
+
+impl<S> Aliases<S> {
+    pub fn new(stylesheet: S) -> Self {
+        Self {
+            stylesheet,
+            aliases: HashMap::new(),
+        }
+    }
+
+    pub fn with_alias(mut self, name: impl Into<String>, classes: impl Into<String>) -> Self {
+        self.aliases.insert(name.into(), classes.into());
+        self
+    }
+}
+
+impl<S: Stylesheet> Aliases<S> {
+    /// Resolves each alias in `names` on its own, and each run of classes between them together,
+    /// then layers the results in order. A class written after an alias is taken as an override
+    /// of it, rather than reported as a conflict with one of its utilities.
+    fn resolve<'a>(&'a self, names: &'a str, chain: &mut Vec<&'a str>) -> Result<Styles, Error> {
+        let mut styles = Styles::default();
+        let mut run = Vec::new();
+        for name in names.split_whitespace() {
+            let Some(classes) = self.aliases.get(name) else {
+                run.push(name);
+                continue;
+            };
+
+            if !run.is_empty() {
+                styles.layer(self.stylesheet.get_styles(&run.join(" "))?);
+                run.clear();
+            }
+
+            let cyclic = chain.contains(&name);
+            chain.push(name);
+            if cyclic {
+                return Err(Error::AliasCycle(chain.join(" -> ")));
+            }
+            styles.layer(self.resolve(classes, chain)?);
+            chain.pop();
+        }
+
+        if !run.is_empty() {
+            styles.layer(self.stylesheet.get_styles(&run.join(" "))?);
+        }
+
+        Ok(styles)
+    }
+}
+
+impl<S: Stylesheet> Stylesheet for Aliases<S> {
+    fn get_styles(&self, names: &str) -> Result<Styles, Error> {
+        self.resolve(names, &mut Vec::new())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FontWeight {
+    Thin,
+    ExtraLight,
+    Light,
+    #[default]
+    Normal,
+    Medium,
+    SemiBold,
+    Bold,
+    ExtraBold,
+    Black,
+}
+
+/// A single font file in the [`Fonts`] registry.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FontFace {
+    pub family: String,
+    pub weight: FontWeight,
+    pub italic: bool,
+}
+
+impl FontFace {
+    pub const DEFAULT_FAMILY: &'static str = "sans";
+
+    pub fn new(family: impl Into<String>) -> Self {
+        FontFace {
+            family: family.into(),
+            weight: FontWeight::Normal,
+            italic: false,
+        }
+    }
+
+    pub fn weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+}
+
+/// The fonts text nodes can pick from with the `font_family`, `font_weight` and `italic`
+/// styles. Faces that haven't been registered fall back to the upright and normal weight faces
+/// of the same family, and finally to the default font.
+#[derive(Clone, Default)]
+pub struct Fonts {
+    default: Handle<Font>,
+    faces: HashMap<FontFace, Handle<Font>>,
+}
+
+impl Fonts {
+    pub fn new(default: Handle<Font>) -> Self {
+        Fonts {
+            default,
+            faces: HashMap::new(),
+        }
+    }
+
+    pub fn with_face(mut self, face: FontFace, font: Handle<Font>) -> Self {
+        self.faces.insert(face, font);
+        self
+    }
+
+    pub fn get(&self, face: &FontFace) -> Handle<Font> {
+        let fallbacks = [
+            face.clone(),
+            FontFace {
+                italic: false,
+                ..face.clone()
+            },
+            FontFace {
+                weight: FontWeight::Normal,
+                ..face.clone()
+            },
+            FontFace::new(face.family.clone()),
+        ];
+
+        fallbacks
+            .iter()
+            .find_map(|face| self.faces.get(face))
+            .unwrap_or(&self.default)
+            .clone()
+    }
+}
+
+/// Adds the systems that rendered nodes rely on, such as mouse-wheel scrolling.
+pub struct FauxDomPlugin;
+
+impl Plugin for FauxDomPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, scroll);
+    }
+}
+
+/// Pixels scrolled per line for mouse wheels that report lines rather than pixels.
+const SCROLL_LINE_HEIGHT: f32 = 20.0;
+
+/// The inner node of a scroll container, offset by the mouse wheel.
+#[derive(Component, Default)]
+pub struct ScrollContent {
+    pub offset: f32,
+}
+
+fn scroll(
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut contents: Query<(&mut ScrollContent, &mut Style, &Parent, &Node)>,
+    containers: Query<(&Node, &RelativeCursorPosition)>,
+) {
+    for event in mouse_wheel_events.read() {
+        let delta = match event.unit {
+            MouseScrollUnit::Line => event.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        };
+
+        for (mut content, mut style, parent, node) in &mut contents {
+            let Ok((container, cursor)) = containers.get(parent.get()) else {
+                continue;
+            };
+
+            if !cursor.mouse_over() {
+                continue;
+            }
+
+            let max_offset = (node.size().y - container.size().y).max(0.0);
+            content.offset = (content.offset + delta).clamp(-max_offset, 0.0);
+            style.top = Val::Px(content.offset);
+        }
+    }
+}
+
+/// Spawns `node` as children of `parent`. Every class is looked up first, so an error from the
+/// stylesheet is returned before anything is spawned, while its warnings are logged along with
+/// the path of the element that caused them.
+pub fn render(
+    node: FauxNode,
+    commands: &mut Commands,
+    parent: Entity,
+    fonts: &Fonts,
+    stylesheet: &impl Stylesheet,
+) -> Result<RenderedTree, Error> {
+    Renderer::new(fonts, stylesheet).render_into(
+        node,
+        &mut Under {
+            target: commands,
+            parent: Some(parent),
+        },
+    )
+}
+
+/// Like [`render`], but leaves the top-level nodes without a parent.
+pub fn render_root(
+    node: FauxNode,
+    commands: &mut Commands,
+    fonts: &Fonts,
+    stylesheet: &impl Stylesheet,
+) -> Result<RenderedTree, Error> {
+    Renderer::new(fonts, stylesheet).render_into(
+        node,
+        &mut Under {
+            target: commands,
+            parent: None,
+        },
+    )
+}
+
+/// Like [`render`], for use inside a `with_children` closure next to hand-written children.
+pub fn render_children(
+    node: FauxNode,
+    builder: &mut ChildBuilder,
+    fonts: &Fonts,
+    stylesheet: &impl Stylesheet,
+) -> Result<RenderedTree, Error> {
+    Renderer::new(fonts, stylesheet).render_into(node, builder)
+}
+
+/// Like [`render`], but spawns straight into `world`, for exclusive systems and tests.
+pub fn render_world(
+    node: FauxNode,
+    world: &mut World,
+    parent: Entity,
+    fonts: &Fonts,
+    stylesheet: &impl Stylesheet,
+) -> Result<RenderedTree, Error> {
+    Renderer::new(fonts, stylesheet).render_into(
+        node,
+        &mut Under {
+            target: world,
+            parent: Some(parent),
+        },
+    )
+}
+
+/// Like [`render_world`], but leaves the top-level nodes without a parent.
+pub fn render_world_root(
+    node: FauxNode,
+    world: &mut World,
+    fonts: &Fonts,
+    stylesheet: &impl Stylesheet,
+) -> Result<RenderedTree, Error> {
+    Renderer::new(fonts, stylesheet).render_into(
+        node,
+        &mut Under {
+            target: world,
+            parent: None,
+        },
+    )
+}
+
+/// Like [`render_world`], for use inside a `with_children` closure on an `EntityWorldMut`.
+pub fn render_world_children(
+    node: FauxNode,
+    builder: &mut WorldChildBuilder,
+    fonts: &Fonts,
+    stylesheet: &impl Stylesheet,
+) -> Result<RenderedTree, Error> {
+    Renderer::new(fonts, stylesheet).render_into(node, builder)
+}
+
+/// The entities spawned by a render call, so the tree can be torn down or swapped out without
+/// touching anything else under its parent.
+#[derive(Clone, Debug, Default)]
+pub struct RenderedTree {
+    /// The entity the top-level nodes were spawned under, if any.
+    pub parent: Option<Entity>,
+    /// The entities of the top-level nodes, in order.
+    pub roots: Vec<Entity>,
+    /// Every entity spawned for the tree, in the order they were spawned.
+    pub entities: Vec<Entity>,
+    /// The entity of every node, by its path in the tree, like `div > text:nth-child(1)`.
+    pub nodes: HashMap<String, Entity>,
+    pub ids: FauxIds,
+}
+
+impl RenderedTree {
+    /// Despawns the tree, along with anything spawned under its nodes since.
+    pub fn despawn(self, commands: &mut Commands) {
+        for root in self.roots {
+            commands.entity(root).despawn_recursive();
+        }
+    }
+
+    /// Renders `node` in place of the tree, at the same position under the same parent.
+    pub fn replace(
+        &mut self,
+        node: FauxNode,
+        commands: &mut Commands,
+        fonts: &Fonts,
+        stylesheet: &impl Stylesheet,
+    ) -> Result<(), Error> {
+        let mut replacement = Renderer::new(fonts, stylesheet).render_into(
+            node,
+            &mut Under {
+                target: commands,
+                parent: None,
+            },
+        )?;
+        replacement.parent = self.parent;
+
+        let old_roots = std::mem::take(&mut self.roots);
+        let new_roots = replacement.roots.clone();
+        let parent = self.parent;
+        commands.add(move |world: &mut World| {
+            if let Some(parent) = parent {
+                let children = world.get::<Children>(parent);
+                let index = children
+                    .and_then(|children| children.iter().position(|c| old_roots.contains(c)))
+                    .unwrap_or_else(|| children.map_or(0, |children| children.len()));
+                world.entity_mut(parent).insert_children(index, &new_roots);
+            }
+            for root in old_roots {
+                despawn_with_children_recursive(world, root);
+            }
+        });
+
+        *self = replacement;
+        Ok(())
+    }
+
+    pub fn hide(&self, commands: &mut Commands) {
+        for &root in &self.roots {
+            commands
+                .entity(root)
+                .insert(bevy::render::view::Visibility::Hidden);
+        }
+    }
+
+    /// Undoes [`RenderedTree::hide`], letting the tree inherit its parent's visibility again.
+    pub fn show(&self, commands: &mut Commands) {
+        for &root in &self.roots {
+            commands
+                .entity(root)
+                .insert(bevy::render::view::Visibility::Inherited);
+        }
+    }
+}
+
+/// The `id` attribute of the element an entity was spawned from.
+#[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FauxId(pub String);
+
+/// The entities spawned by a render call, by the `id` of their element. Insert it on the root,
+/// or keep it in a resource, so systems can reach specific nodes directly.
+#[derive(Component, Resource, Clone, Debug, Default)]
+pub struct FauxIds(pub HashMap<String, Entity>);
+
+impl FauxIds {
+    pub fn get(&self, id: &str) -> Option<Entity> {
+        self.0.get(id).copied()
+    }
+}
+
+/// A handle created before `rsx!` and passed to an element as `ref={handle}`, which holds the
+/// element's entity once it has been rendered. Clones share the same slot, and compare equal.
+#[derive(Clone, Debug, Default)]
+pub struct FauxRef(Arc<Mutex<Option<Entity>>>);
+
+impl PartialEq for FauxRef {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for FauxRef {}
+
+impl FauxRef {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The entity of the element, or `None` if it hasn't been rendered yet.
+    pub fn get(&self) -> Option<Entity> {
+        *self.0.lock().unwrap()
+    }
+
+    fn set(&self, entity: Entity) {
+        *self.0.lock().unwrap() = Some(entity);
+    }
+}
+
+/// The `data-*` attributes of the element an entity was spawned from, so generic UI systems can
+/// find nodes by item id, slot index and the like.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FauxData(pub HashMap<String, String>);
+
+impl FauxData {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+}
+
+/// A bundle given to an element as `components={(MainMenuRoot, Tooltip::new("..."))}`, kept until
+/// the element is spawned. A clone of the bundle is inserted every time the element is rendered,
+/// so a cloned tree renders the same as the original; bundles that can't be cloned go through
+/// [`FauxComponents::once`]. Clones share the same bundle, and compare equal.
+#[derive(Clone)]
+pub struct FauxComponents(Arc<dyn Fn(&mut EntityWorldMut) + Send + Sync>);
+
+impl<B: Bundle + Clone> From<B> for FauxComponents {
+    fn from(bundle: B) -> Self {
+        Self(Arc::new(move |entity: &mut EntityWorldMut| {
+            entity.insert(bundle.clone());
+        }))
+    }
+}
+
+impl FauxComponents {
+    /// Moves `bundle` into the first entity rendered from the element. Rendering the element
+    /// again, e.g. from a clone of the tree, warns and leaves the bundle out.
+    pub fn once(bundle: impl Bundle) -> Self {
+        let bundle = Mutex::new(Some(bundle));
+        Self(Arc::new(move |entity: &mut EntityWorldMut| {
+            match bundle.lock().unwrap().take() {
+                Some(bundle) => {
+                    entity.insert(bundle);
+                }
+                None => warn!(
+                    "{:?}: components were already inserted by an earlier render",
+                    entity.id()
+                ),
+            }
+        }))
+    }
+}
+
+impl PartialEq for FauxComponents {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for FauxComponents {}
+
+impl fmt::Debug for FauxComponents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FauxComponents").finish_non_exhaustive()
+    }
+}
+
+/// Where a [`Renderer`] spawns entities: deferred through [`Commands`], or straight into a
+/// [`World`].
+trait Spawner {
+    /// Spawns an empty entity, as the last child of `parent` if there is one.
+    fn spawn_child(&mut self, parent: Option<Entity>) -> Entity;
+    fn insert(&mut self, entity: Entity, bundle: impl Bundle);
+    fn insert_components(&mut self, entity: Entity, components: FauxComponents);
+}
+
+impl Spawner for Commands<'_, '_> {
+    fn spawn_child(&mut self, parent: Option<Entity>) -> Entity {
+        let entity = self.spawn_empty().id();
+        if let Some(parent) = parent {
+            self.entity(parent).add_child(entity);
+        }
+        entity
+    }
 
-pub struct FauxStyle {
-    pub style: bevy::ui::Style,
-    pub background_color: Option<bevy::ui::BackgroundColor>,
-    pub font_size: Option<f32>,
-    pub color: Option<bevy::render::color::Color>,
+    fn insert(&mut self, entity: Entity, bundle: impl Bundle) {
+        self.entity(entity).insert(bundle);
+    }
+
+    fn insert_components(&mut self, entity: Entity, components: FauxComponents) {
+        self.add(move |world: &mut World| (components.0)(&mut world.entity_mut(entity)));
+    }
 }
 
-#[derive(Clone)]
-struct CascadingStyle {
-    font: Handle<Font>,
-    font_size: Option<f32>,
-    color: Option<bevy::render::color::Color>,
+impl Spawner for World {
+    fn spawn_child(&mut self, parent: Option<Entity>) -> Entity {
+        let entity = self.spawn_empty().id();
+        if let Some(parent) = parent {
+            self.entity_mut(parent).add_child(entity);
+        }
+        entity
+    }
+
+    fn insert(&mut self, entity: Entity, bundle: impl Bundle) {
+        self.entity_mut(entity).insert(bundle);
+    }
+
+    fn insert_components(&mut self, entity: Entity, components: FauxComponents) {
+        (components.0)(&mut self.entity_mut(entity));
+    }
 }
 
-pub trait Stylesheet {
-    fn get_styles(&self, names: &str) -> FauxStyle;
+/// Where the top-level nodes of a render are spawned, each into an entity of its own.
+trait Roots {
+    type Target: Spawner;
+
+    fn parent(&self) -> Option<Entity>;
+    /// Spawns an empty entity for a top-level node and hands it to `f`, along with the
+    /// [`Spawner`] to fill it in with.
+    fn with_root<R>(&mut self, f: impl FnOnce(&mut Self::Target, Entity) -> R) -> R;
 }
 
-pub fn render(
-    node: FauxNode,
-    commands: &mut Commands,
-    parent: Entity,
-    font: &Handle<Font>,
-    stylesheet: &impl Stylesheet,
-) {
-    return render_cascading(
-        node,
-        commands,
-        parent,
-        stylesheet,
-        &CascadingStyle {
-            font: font.clone(),
-            font_size: None,
-            color: None,
-        },
-    );
+/// Spawns the top-level nodes straight into `target`, under `parent` if there is one.
+struct Under<'a, T> {
+    target: &'a mut T,
+    parent: Option<Entity>,
 }
 
-fn render_cascading(
-    node: FauxNode,
-    commands: &mut Commands,
-    parent: Entity,
-    stylesheet: &impl Stylesheet,
-    cascading_styles: &CascadingStyle,
-) {
+impl<T: Spawner> Roots for Under<'_, T> {
+    type Target = T;
+
+    fn parent(&self) -> Option<Entity> {
+        self.parent
+    }
+
+    fn with_root<R>(&mut self, f: impl FnOnce(&mut T, Entity) -> R) -> R {
+        let entity = self.target.spawn_child(self.parent);
+        f(self.target, entity)
+    }
+}
+
+impl<'w, 's> Roots for ChildBuilder<'w, 's, '_> {
+    type Target = Commands<'w, 's>;
+
+    fn parent(&self) -> Option<Entity> {
+        Some(self.parent_entity())
+    }
+
+    fn with_root<R>(&mut self, f: impl FnOnce(&mut Commands<'w, 's>, Entity) -> R) -> R {
+        // A `ChildBuilder` only hands out its `Commands` through the entities it spawns.
+        let mut entity = self.spawn_empty();
+        let id = entity.id();
+        f(entity.commands(), id)
+    }
+}
+
+impl Roots for WorldChildBuilder<'_> {
+    type Target = World;
+
+    fn parent(&self) -> Option<Entity> {
+        Some(self.parent_entity())
+    }
+
+    fn with_root<R>(&mut self, f: impl FnOnce(&mut World, Entity) -> R) -> R {
+        let entity = self.spawn_empty();
+        let id = entity.id();
+        f(entity.into_world_mut(), id)
+    }
+}
+
+/// Splits fragments up so that every node left gets an entity of its own.
+fn flatten(node: FauxNode) -> Vec<FauxNode> {
+    match node {
+        FauxNode::Fragment(children) => children.into_iter().flat_map(flatten).collect(),
+        node => vec![node],
+    }
+}
+
+/// Like [`flatten`], without taking the tree apart.
+fn flattened(node: &FauxNode) -> Vec<&FauxNode> {
     match node {
-        FauxNode::Text(text) => {
-            commands.entity(parent).with_children(|parent| {
+        FauxNode::Fragment(children) => children.iter().flat_map(flattened).collect(),
+        node => vec![node],
+    }
+}
+
+/// A CSS-like path to the `index`th of `count` siblings, used in warnings and
+/// [`RenderedTree::nodes`].
+fn node_path(parent: Option<&str>, node: &FauxNode, index: usize, count: usize) -> String {
+    let tag = match node {
+        FauxNode::Text(_) => "text",
+        _ => "div",
+    };
+
+    match parent {
+        None if count == 1 => tag.to_string(),
+        None => format!("{tag}:nth-child({})", index + 1),
+        Some(parent) => format!("{parent} > {tag}:nth-child({})", index + 1),
+    }
+}
+
+/// The state shared by every node of a single render call.
+struct Renderer<'a, S> {
+    fonts: &'a Fonts,
+    stylesheet: &'a S,
+    /// The styles of every element with a class, by path, looked up before anything is spawned.
+    styles: HashMap<String, Styles>,
+    /// The ids seen while looking up styles, to catch duplicates before anything is spawned.
+    ids: HashSet<String>,
+    tree: RenderedTree,
+}
+
+impl<'a, S: Stylesheet> Renderer<'a, S> {
+    fn new(fonts: &'a Fonts, stylesheet: &'a S) -> Self {
+        Self {
+            fonts,
+            stylesheet,
+            styles: HashMap::new(),
+            ids: HashSet::new(),
+            tree: RenderedTree::default(),
+        }
+    }
+
+    fn render_into(
+        mut self,
+        node: FauxNode,
+        roots: &mut impl Roots,
+    ) -> Result<RenderedTree, Error> {
+        self.tree.parent = roots.parent();
+        let nodes = flatten(node);
+        let count = nodes.len();
+
+        // Stylesheet errors and duplicate ids are hit before the first entity is spawned, so a
+        // failed render leaves nothing behind.
+        for (index, node) in nodes.iter().enumerate() {
+            self.resolve(node, &node_path(None, node, index, count))?;
+        }
+
+        for (index, node) in nodes.into_iter().enumerate() {
+            let path = node_path(None, &node, index, count);
+            roots.with_root(|target, entity| self.render_root(node, target, entity, &path));
+        }
+        Ok(self.tree)
+    }
+
+    /// Looks up the styles of `node` and all its descendants, and checks their ids are unique.
+    fn resolve(&mut self, node: &FauxNode, path: &str) -> Result<(), Error> {
+        if let FauxNode::Div(children, properties) = node {
+            if let Some(id) = &properties.id {
+                if !self.ids.insert(id.clone()) {
+                    return Err(Error::DuplicateId(id.clone()));
+                }
+            }
+
+            if let Some(class) = &properties.class {
+                let styles = self.stylesheet.get_styles(class)?;
+                self.styles.insert(path.to_string(), styles);
+            }
+
+            let children: Vec<_> = children.iter().flat_map(flattened).collect();
+            let count = children.len();
+            for (index, child) in children.into_iter().enumerate() {
+                self.resolve(child, &node_path(Some(path), child, index, count))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render_root(
+        &mut self,
+        node: FauxNode,
+        target: &mut impl Spawner,
+        entity: Entity,
+        path: &str,
+    ) {
+        self.tree.roots.push(entity);
+        self.render_cascading(
+            node,
+            target,
+            entity,
+            &CascadingStyle {
+                font_family: None,
+                font_weight: None,
+                italic: None,
+                font_size: None,
+                color: None,
+                text_alignment: None,
+                linebreak_behavior: None,
+                opacity: 1.0,
+            },
+            path,
+        )
+    }
+
+    /// Fills `entity`, spawned beforehand, with `node` and spawns its children.
+    fn render_cascading(
+        &mut self,
+        node: FauxNode,
+        target: &mut impl Spawner,
+        entity: Entity,
+        cascading_styles: &CascadingStyle,
+        path: &str,
+    ) {
+        self.tree.entities.push(entity);
+        self.tree.nodes.insert(path.to_string(), entity);
+
+        match node {
+            FauxNode::Text(text) => {
                 let mut bundle = TextBundle {
                     text: Text::from_section(
                         text,
                         TextStyle {
-                            font: cascading_styles.font.clone(),
+                            font: self.fonts.get(&cascading_styles.font_face()),
                             font_size: cascading_styles
                                 .font_size
                                 .unwrap_or(TextStyle::default().font_size),
-                            color: cascading_styles.color.unwrap_or(TextStyle::default().color),
+                            color: fade(
+                                cascading_styles.color.unwrap_or(TextStyle::default().color),
+                                cascading_styles.opacity,
+                            ),
                         },
                     ),
                     ..Default::default()
                 };
 
-                parent.spawn(bundle);
-            });
-        }
-        FauxNode::Div(children, properties) => {
-            let mut bundle = NodeBundle {
-                ..Default::default()
-            };
+                if let Some(text_alignment) = cascading_styles.text_alignment {
+                    bundle.text.alignment = text_alignment;
+                }
+
+                if let Some(linebreak_behavior) = cascading_styles.linebreak_behavior {
+                    bundle.text.linebreak_behavior = linebreak_behavior;
+                }
+
+                target.insert(entity, bundle);
+            }
+            FauxNode::Div(children, properties) => {
+                let mut bundle = NodeBundle {
+                    ..Default::default()
+                };
 
-            let mut next_cascading_styles = cascading_styles.clone();
+                let mut next_cascading_styles = cascading_styles.clone();
+                let mut styles = FauxStyle::default();
 
-            if let Some(class) = properties.class {
-                let styles = stylesheet.get_styles(class.as_str());
-                bundle.style = styles.style;
+                if let Some(Styles { style, warnings }) = self.styles.remove(path) {
+                    for warning in warnings {
+                        warn!("{path}: {warning}");
+                    }
+
+                    styles.merge(style);
+                }
+
+                if let Some(style) = properties.style {
+                    styles.merge(*style);
+                }
+
+                styles.apply_to(&mut bundle.style);
 
                 if let Some(background_color) = styles.background_color {
-                    bundle.background_color = background_color.clone();
+                    bundle.background_color = background_color;
+                }
+
+                if let Some(border_color) = styles.border_color {
+                    bundle.border_color = border_color;
+                }
+
+                if let Some(visibility) = styles.visibility {
+                    bundle.visibility = visibility;
+                }
+
+                if let Some(z_index) = styles.z_index {
+                    bundle.z_index = z_index;
+                }
+
+                if let Some(opacity) = styles.opacity {
+                    next_cascading_styles.opacity *= opacity;
                 }
 
                 if styles.font_size.is_some() {
@@ -96,27 +1728,661 @@ fn render_cascading(
                 if styles.color.is_some() {
                     next_cascading_styles.color = styles.color;
                 }
+
+                if styles.text_alignment.is_some() {
+                    next_cascading_styles.text_alignment = styles.text_alignment;
+                }
+
+                if styles.linebreak_behavior.is_some() {
+                    next_cascading_styles.linebreak_behavior = styles.linebreak_behavior;
+                }
+
+                if styles.font_family.is_some() {
+                    next_cascading_styles.font_family = styles.font_family;
+                }
+
+                if styles.font_weight.is_some() {
+                    next_cascading_styles.font_weight = styles.font_weight;
+                }
+
+                if styles.italic.is_some() {
+                    next_cascading_styles.italic = styles.italic;
+                }
+
+                let mut outline = styles.outline_width.map(|width| {
+                    Outline::new(
+                        width,
+                        styles.outline_offset.unwrap_or(Val::ZERO),
+                        styles
+                            .outline_color
+                            .or(next_cascading_styles.color)
+                            .unwrap_or(Color::BLACK),
+                    )
+                });
+                let scroll_y = styles.scroll_y.unwrap_or(false);
+
+                let opacity = next_cascading_styles.opacity;
+                bundle.background_color.0 = fade(bundle.background_color.0, opacity);
+                bundle.border_color.0 = fade(bundle.border_color.0, opacity);
+                if let Some(outline) = &mut outline {
+                    outline.color = fade(outline.color, opacity);
+                }
+
+                let scroll_content = scroll_y.then(|| scroll_content(&mut bundle.style));
+
+                target.insert(entity, bundle);
+
+                if let Some(outline) = outline {
+                    target.insert(entity, outline);
+                }
+
+                if let Some(name) = properties.name.or_else(|| properties.id.clone()) {
+                    target.insert(entity, Name::new(name));
+                }
+
+                if let Some(components) = properties.components {
+                    target.insert_components(entity, components);
+                }
+
+                if !properties.data.is_empty() {
+                    target.insert(entity, FauxData(properties.data));
+                }
+
+                if let Some(reference) = properties.reference {
+                    reference.set(entity);
+                }
+
+                if let Some(id) = properties.id {
+                    self.tree.ids.0.insert(id.clone(), entity);
+                    target.insert(entity, FauxId(id));
+                }
+
+                let children_parent = match scroll_content {
+                    Some(content) => {
+                        target.insert(entity, RelativeCursorPosition::default());
+                        let content_entity = target.spawn_child(Some(entity));
+                        self.tree.entities.push(content_entity);
+                        target.insert(content_entity, (content, ScrollContent::default()));
+                        content_entity
+                    }
+                    None => entity,
+                };
+
+                let children: Vec<_> = children.into_iter().flat_map(flatten).collect();
+                let count = children.len();
+                for (index, child) in children.into_iter().enumerate() {
+                    let child_entity = target.spawn_child(Some(children_parent));
+                    let child_path = node_path(Some(path), &child, index, count);
+                    self.render_cascading(
+                        child,
+                        target,
+                        child_entity,
+                        &next_cascading_styles,
+                        &child_path,
+                    );
+                }
             }
+            FauxNode::Fragment(_) => unreachable!("fragments are flattened before rendering"),
+        }
+    }
+}
+
+fn fade(color: Color, opacity: f32) -> Color {
+    color.with_a(color.a() * opacity)
+}
 
-            let div_entity = commands.spawn(bundle).id();
+/// Clips `style` vertically so it can act as a scroll viewport, and returns the inner node that
+/// the viewport's children are spawned into. The inner node takes over the viewport's flex or
+/// grid layout and its padding, so children are arranged as if they were spawned directly into
+/// it, while the viewport is left stacking the inner node across its full width.
+fn scroll_content(style: &mut Style) -> NodeBundle {
+    style.overflow.y = OverflowAxis::Clip;
 
-            commands.entity(parent).add_child(div_entity);
+    let content = NodeBundle {
+        style: Style {
+            display: style.display,
+            flex_direction: style.flex_direction,
+            flex_wrap: style.flex_wrap,
+            align_items: style.align_items,
+            justify_items: style.justify_items,
+            align_content: style.align_content,
+            justify_content: style.justify_content,
+            row_gap: style.row_gap,
+            column_gap: style.column_gap,
+            grid_auto_flow: style.grid_auto_flow,
+            grid_template_rows: std::mem::take(&mut style.grid_template_rows),
+            grid_template_columns: std::mem::take(&mut style.grid_template_columns),
+            grid_auto_rows: std::mem::take(&mut style.grid_auto_rows),
+            grid_auto_columns: std::mem::take(&mut style.grid_auto_columns),
+            padding: std::mem::take(&mut style.padding),
+            flex_shrink: 0.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
 
-            for child in children {
-                render_cascading(
-                    child,
-                    commands,
-                    div_entity,
-                    stylesheet,
-                    &next_cascading_styles,
-                );
+    if style.display != bevy::ui::Display::None {
+        style.display = bevy::ui::Display::Flex;
+    }
+    style.flex_direction = bevy::ui::FlexDirection::Column;
+    style.align_items = bevy::ui::AlignItems::Stretch;
+    style.justify_content = bevy::ui::JustifyContent::FlexStart;
+
+    content
+}
+
+/// A windowless app that renders trees and runs UI layout on them, so tests can check computed
+/// sizes and text without a GPU. The primary window is never opened, but layout is still
+/// resolved against its default 1280x720 size.
+pub struct TestApp {
+    pub app: App,
+    pub fonts: Fonts,
+    ids: FauxIds,
+}
+
+impl Default for TestApp {
+    fn default() -> Self {
+        TestApp::new()
+    }
+}
+
+impl TestApp {
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            WindowPlugin::default(),
+            InputPlugin,
+            TransformPlugin,
+            HierarchyPlugin,
+            ImagePlugin::default(),
+            TextPlugin,
+        ))
+        // Normally registered by the render and sprite plugins, which need a GPU.
+        .init_asset::<Shader>()
+        .init_asset::<TextureAtlas>()
+        .add_plugins((UiPlugin, FauxDomPlugin));
+        app.finish();
+        app.cleanup();
+
+        TestApp {
+            app,
+            fonts: Fonts::default(),
+            ids: FauxIds::default(),
+        }
+    }
+
+    /// Renders `node` as a new root and runs a frame, so its layout is ready to be checked.
+    pub fn render(
+        &mut self,
+        node: FauxNode,
+        stylesheet: &impl Stylesheet,
+    ) -> Result<RenderedTree, Error> {
+        let tree = render_world_root(node, &mut self.app.world, &self.fonts, stylesheet)?;
+        self.ids.0.extend(tree.ids.0.clone());
+        self.update();
+        Ok(tree)
+    }
+
+    pub fn update(&mut self) {
+        self.app.update();
+    }
+
+    pub fn entity(&self, id: &str) -> Entity {
+        self.ids
+            .get(id)
+            .unwrap_or_else(|| panic!("no node has the id `{id}`"))
+    }
+
+    /// The computed size of the node with the given id, in logical pixels.
+    pub fn size(&self, id: &str) -> Vec2 {
+        let entity = self.entity(id);
+        self.app
+            .world
+            .get::<Node>(entity)
+            .unwrap_or_else(|| panic!("`{id}` is not a UI node"))
+            .size()
+    }
+
+    /// The text of the node with the given id and everything below it, in tree order.
+    pub fn text(&self, id: &str) -> String {
+        let mut text = String::new();
+        self.collect_text(self.entity(id), &mut text);
+        text
+    }
+
+    fn collect_text(&self, entity: Entity, text: &mut String) {
+        if let Some(sections) = self.app.world.get::<Text>(entity) {
+            text.extend(
+                sections
+                    .sections
+                    .iter()
+                    .map(|section| section.value.as_str()),
+            );
+        }
+        if let Some(children) = self.app.world.get::<Children>(entity) {
+            for &child in children {
+                self.collect_text(child, text);
             }
         }
-        FauxNode::Fragment(children) => {
-            for child in children {
-                render_cascading(child, commands, parent, stylesheet, cascading_styles);
+    }
+
+    #[track_caller]
+    pub fn assert_size(&self, id: &str, width: f32, height: f32) {
+        let size = self.size(id);
+        assert!(
+            size.abs_diff_eq(Vec2::new(width, height), 0.5),
+            "`{id}` is {}x{}, expected {width}x{height}",
+            size.x,
+            size.y,
+        );
+    }
+
+    #[track_caller]
+    pub fn assert_text(&self, id: &str, text: &str) {
+        assert_eq!(self.text(id), text, "text of `{id}`");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use faux_dom_macro::rsx;
+
+    use super::*;
+
+    /// A small stylesheet with `flex`, `hidden`, `w-{px}`, `h-{px}`, `p-{px}` and `pt-{px}`.
+    /// Unknown classes and classes that set a property an earlier one did are warnings, while
+    /// malformed numbers are errors.
+    struct Utilities;
+
+    impl Stylesheet for Utilities {
+        fn get_styles(&self, names: &str) -> Result<Styles, Error> {
+            let mut styles = Styles::default();
+            let mut set_by = HashMap::new();
+            for name in names.split_whitespace() {
+                let style = &mut styles.style;
+                let px = |value: &str| {
+                    value
+                        .parse()
+                        .map(Val::Px)
+                        .map_err(|_| Error::MalformedValue(name.to_string()))
+                };
+                let properties: &[&str] = match (name, name.split_once('-')) {
+                    ("flex", _) => {
+                        style.display = Some(bevy::ui::Display::Flex);
+                        &["display"]
+                    }
+                    ("hidden", _) => {
+                        style.display = Some(bevy::ui::Display::None);
+                        &["display"]
+                    }
+                    (_, Some(("w", value))) => {
+                        style.width = Some(px(value)?);
+                        &["width"]
+                    }
+                    (_, Some(("h", value))) => {
+                        style.height = Some(px(value)?);
+                        &["height"]
+                    }
+                    (_, Some(("p", value))) => {
+                        style.padding = FauxRect::all(px(value)?);
+                        &[
+                            "padding-top",
+                            "padding-right",
+                            "padding-bottom",
+                            "padding-left",
+                        ]
+                    }
+                    (_, Some(("pt", value))) => {
+                        style.padding.top = Some(px(value)?);
+                        &["padding-top"]
+                    }
+                    _ => {
+                        styles
+                            .warnings
+                            .push(Error::UnknownUtility(name.to_string()));
+                        continue;
+                    }
+                };
+
+                let mut overridden = Vec::new();
+                for property in properties {
+                    if let Some(previous) = set_by.insert(*property, name) {
+                        if !overridden.contains(&previous) {
+                            overridden.push(previous);
+                        }
+                    }
+                }
+                for previous in overridden {
+                    styles.warnings.push(Error::Conflict {
+                        class: name.to_string(),
+                        previous: previous.to_string(),
+                    });
+                }
             }
+
+            Ok(styles)
         }
-        FauxNode::Expr(_) => panic!("Expr not supported"),
+    }
+
+    #[test]
+    fn classes_only_set_what_they_specify() {
+        let styles = Utilities.get_styles("pt-2 w-4").unwrap().style;
+
+        assert_eq!(styles.padding.top, Some(Val::Px(2.0)));
+        assert_eq!(styles.padding.left, None);
+        assert_eq!(styles.width, Some(Val::Px(4.0)));
+        assert_eq!(styles.height, None);
+        assert_eq!(styles.display, None);
+    }
+
+    #[test]
+    fn merge_layers_set_fields_on_top() {
+        let mut styles = Utilities.get_styles("p-4 w-4 hidden").unwrap().style;
+        styles.merge(Utilities.get_styles("pt-2 h-8").unwrap().style);
+
+        assert_eq!(styles.padding.top, Some(Val::Px(2.0)));
+        assert_eq!(styles.padding.left, Some(Val::Px(4.0)));
+        assert_eq!(styles.width, Some(Val::Px(4.0)));
+        assert_eq!(styles.height, Some(Val::Px(8.0)));
+        assert_eq!(styles.display, Some(bevy::ui::Display::None));
+
+        let mut style = Style::default();
+        styles.apply_to(&mut style);
+        assert_eq!(style.padding.top, Val::Px(2.0));
+        assert_eq!(style.padding.bottom, Val::Px(4.0));
+        assert_eq!(style.display, bevy::ui::Display::None);
+        assert_eq!(style.min_width, Val::Auto);
+    }
+
+    #[test]
+    fn inline_faux_style_only_sets_its_fields() {
+        let mut styles = Utilities.get_styles("w-4 h-4").unwrap().style;
+        styles.merge(FauxStyle {
+            width: Some(Val::Percent(50.0)),
+            ..Default::default()
+        });
+
+        assert_eq!(styles.width, Some(Val::Percent(50.0)));
+        assert_eq!(styles.height, Some(Val::Px(4.0)));
+        assert_eq!(styles.display, None);
+    }
+
+    #[test]
+    fn inline_style_overrides_back_to_defaults() {
+        let mut styles = Utilities.get_styles("hidden p-4").unwrap().style;
+        styles.merge(FauxStyle {
+            display: Some(bevy::ui::Display::Flex),
+            padding: FauxRect::all(Val::ZERO),
+            ..Default::default()
+        });
+
+        let mut style = Style::default();
+        styles.apply_to(&mut style);
+        assert_eq!(style.display, bevy::ui::Display::Flex);
+        assert_eq!(style.padding, UiRect::all(Val::ZERO));
+    }
+
+    #[test]
+    fn inline_style_keeps_class_layout() {
+        let mut styles = Utilities.get_styles("hidden p-4 h-8").unwrap().style;
+        styles.merge(FauxStyle::from(Style {
+            width: Val::Percent(50.0),
+            ..Default::default()
+        }));
+
+        assert_eq!(styles.width, Some(Val::Percent(50.0)));
+        assert_eq!(styles.height, Some(Val::Px(8.0)));
+        assert_eq!(styles.padding.top, Some(Val::Px(4.0)));
+        assert_eq!(styles.display, Some(bevy::ui::Display::None));
+    }
+
+    #[test]
+    fn strict_mode_accepts_clean_classes() {
+        let styles = Strict(Utilities).get_styles("flex  p-4 w-8").unwrap();
+
+        assert!(styles.warnings.is_empty());
+    }
+
+    #[test]
+    fn strict_mode_turns_warnings_into_errors() {
+        assert_eq!(
+            Strict(Utilities).get_styles("flex flexx").err(),
+            Some(Error::UnknownUtility("flexx".to_string()))
+        );
+    }
+
+    #[test]
+    fn aliases_expand_recursively() {
+        let stylesheet = Aliases::new(Utilities)
+            .with_alias("btn", "p-4 h-8")
+            .with_alias("btn-primary", "btn w-64");
+        let styles = stylesheet.get_styles("btn-primary pt-2").unwrap();
+
+        assert!(styles.warnings.is_empty());
+        assert_eq!(styles.style.padding.left, Some(Val::Px(4.0)));
+        assert_eq!(styles.style.padding.top, Some(Val::Px(2.0)));
+        assert_eq!(styles.style.width, Some(Val::Px(64.0)));
+        assert_eq!(styles.style.height, Some(Val::Px(8.0)));
+    }
+
+    #[test]
+    fn classes_after_an_alias_override_it() {
+        let stylesheet = Strict(Aliases::new(Utilities).with_alias("btn", "p-4 h-8 p-2"));
+        assert_eq!(
+            stylesheet.get_styles("btn").err(),
+            Some(Error::Conflict {
+                class: "p-2".to_string(),
+                previous: "p-4".to_string(),
+            })
+        );
+
+        let stylesheet = Aliases::new(Utilities).with_alias("btn", "p-4 h-8");
+        let styles = stylesheet.get_styles("btn pt-8").unwrap();
+        assert!(styles.warnings.is_empty());
+        assert_eq!(styles.style.padding.top, Some(Val::Px(8.0)));
+        assert_eq!(styles.style.padding.left, Some(Val::Px(4.0)));
+        assert!(Strict(stylesheet).get_styles("btn pt-8").is_ok());
+    }
+
+    #[test]
+    fn aliases_layer_over_each_other() {
+        let base = Aliases::new(Utilities).with_alias("panel", "p-4");
+        let stylesheet = Aliases::new(base).with_alias("card", "panel w-32");
+        let styles = stylesheet.get_styles("card").unwrap();
+
+        assert_eq!(styles.style.padding.top, Some(Val::Px(4.0)));
+        assert_eq!(styles.style.width, Some(Val::Px(32.0)));
+    }
+
+    #[test]
+    fn alias_cycles_are_errors() {
+        let stylesheet = Aliases::new(Utilities)
+            .with_alias("a", "flex b")
+            .with_alias("b", "a");
+
+        assert_eq!(
+            stylesheet.get_styles("a").err(),
+            Some(Error::AliasCycle("a -> b -> a".to_string()))
+        );
+    }
+
+    #[test]
+    fn classes_size_nodes() {
+        let mut app = TestApp::new();
+        app.render(rsx! { <div id="box" class="w-64 h-32"></div> }, &Utilities)
+            .unwrap();
+
+        app.assert_size("box", 64.0, 32.0);
+    }
+
+    #[test]
+    fn percentages_resolve_against_the_parent() {
+        let mut app = TestApp::new();
+        app.render(
+            rsx! {
+                <div class="w-200 h-100">
+                    <div id="half" style={Style { width: Val::Percent(50.0), ..Default::default() }}></div>
+                </div>
+            },
+            &Utilities,
+        )
+        .unwrap();
+
+        app.assert_size("half", 100.0, 100.0);
+    }
+
+    #[test]
+    fn text_is_collected_in_tree_order() {
+        let mut app = TestApp::new();
+        app.render(
+            rsx! { <div id="greeting">"hello" <div>" world"</div></div> },
+            &Utilities,
+        )
+        .unwrap();
+
+        app.assert_text("greeting", "hello world");
+        assert!(app.size("greeting").x > 0.0);
+    }
+
+    #[test]
+    fn snapshots_list_resolved_styles() {
+        let node = rsx! {
+            <div id="card" class="w-64 h-32" data-kind="card">
+                "hello"
+                <div style={Style { padding: UiRect::left(Val::Px(4.0)), ..Default::default() }}></div>
+                <div class="w-x"></div>
+            </div>
+        };
+
+        assert_eq!(
+            snapshot(&node, &Utilities),
+            concat!(
+                "div id=\"card\" class=\"w-64 h-32\" data-kind=\"card\"\n",
+                "  width: Px(64.0)\n",
+                "  height: Px(32.0)\n",
+                "  \"hello\"\n",
+                "  div\n",
+                "    padding: FauxRect { left: Px(4.0) }\n",
+                "  div class=\"w-x\"\n",
+                "    error: malformed value in `w-x`\n",
+            )
+        );
+    }
+
+    #[test]
+    fn display_lists_inline_styles() {
+        let node = rsx! {
+            <div name="row" style={FauxStyle { flex_grow: Some(1.0), ..Default::default() }}>"hi"</div>
+        };
+
+        assert_eq!(
+            node.to_string(),
+            "div name=\"row\"\n  flex_grow: 1.0\n  \"hi\"\n"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                FauxRect {
+                    top: Some(Val::Px(1.0)),
+                    ..Default::default()
+                }
+            ),
+            "FauxRect { top: Px(1.0) }"
+        );
+    }
+
+    #[test]
+    fn stylesheet_errors_spawn_nothing() {
+        let mut app = TestApp::new();
+        let result = app.render(
+            rsx! { <div class="w-10">"fine" <div class="w-x"></div></div> },
+            &Utilities,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::MalformedValue("w-x".to_string())
+        );
+        let world = &mut app.app.world;
+        assert_eq!(world.query::<&Node>().iter(world).count(), 0);
+    }
+
+    #[test]
+    fn duplicate_ids_spawn_nothing() {
+        let mut app = TestApp::new();
+        let result = app.render(rsx! { <div id="a"><div id="a"></div></div> }, &Utilities);
+
+        assert_eq!(result.unwrap_err(), Error::DuplicateId("a".to_string()));
+        let world = &mut app.app.world;
+        assert_eq!(world.query::<&Node>().iter(world).count(), 0);
+    }
+
+    #[test]
+    fn scroll_containers_keep_their_grid() {
+        let mut app = TestApp::new();
+        app.render(
+            rsx! {
+                <div class="w-400 h-100" style={FauxStyle {
+                    display: Some(bevy::ui::Display::Grid),
+                    grid_template_columns: Some(bevy::ui::RepeatedGridTrack::flex(4, 1.0)),
+                    padding: FauxRect::all(Val::Px(10.0)),
+                    scroll_y: Some(true),
+                    ..Default::default()
+                }}>
+                    <div id="first" class="h-30"></div>
+                    <div class="h-30"></div>
+                    <div class="h-30"></div>
+                    <div class="h-30"></div>
+                    <div id="fifth" class="h-30"></div>
+                </div>
+            },
+            &Utilities,
+        )
+        .unwrap();
+
+        app.assert_size("first", 95.0, 30.0);
+        app.assert_size("fifth", 95.0, 30.0);
+    }
+
+    #[test]
+    fn cloned_trees_render_again() {
+        #[derive(Component, Clone)]
+        struct Marker;
+        #[derive(Component)]
+        struct Once;
+
+        let node = rsx! {
+            <div id="marker" components={Marker}>
+                <div id="once" components={FauxComponents::once(Once)}></div>
+            </div>
+        };
+        let copy = node.clone();
+        assert_eq!(node, copy);
+
+        let mut app = TestApp::new();
+        let first = app.render(node, &Utilities).unwrap();
+        let second = app.render(copy, &Utilities).unwrap();
+        let world = &app.app.world;
+        assert!(world
+            .get::<Marker>(first.ids.get("marker").unwrap())
+            .is_some());
+        assert!(world
+            .get::<Marker>(second.ids.get("marker").unwrap())
+            .is_some());
+        assert!(world.get::<Once>(first.ids.get("once").unwrap()).is_some());
+        assert!(world.get::<Once>(second.ids.get("once").unwrap()).is_none());
+    }
+
+    #[test]
+    fn structural_equality_ignores_text_whitespace() {
+        let a = rsx! { <div class="w-1">"hello   world"</div> };
+        let b = rsx! { <div class="w-1">" hello\nworld "</div> };
+        let c = rsx! { <div class="w-2">"hello world"</div> };
+
+        assert_ne!(a, b);
+        assert!(a.structurally_eq(&b));
+        assert!(!a.structurally_eq(&c));
     }
 }
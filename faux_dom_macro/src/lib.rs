@@ -3,9 +3,9 @@ use faux_dom_shared::{FauxNode, Properties};
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::quote;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
-use syn::token::Token;
-use syn::{parse_macro_input, token, ExprBlock, LitStr, Token};
+use syn::{braced, parse_macro_input, token, Expr, ExprBlock, LitStr, Token};
 
 struct RsxInput {
     nodes: Vec<FauxNode>,
@@ -52,13 +52,48 @@ fn parse_tag(input: &ParseStream) -> Result<FauxNode, syn::Error> {
 }
 
 fn parse_properties(input: &ParseStream) -> Result<Properties, syn::Error> {
-    let mut properties = Properties { class: None };
+    let mut properties = Properties {
+        id: None,
+        name: None,
+        reference: None,
+        components: None,
+        class: None,
+        style: None,
+        data: Vec::new(),
+    };
     while !input.peek(Token![>]) {
-        let property = input.parse::<Ident>()?;
+        // `ref` is a keyword, so accept any identifier here.
+        let property = input.call(Ident::parse_any)?;
+        // `data-*` attributes are identifiers joined by dashes, like `data-item-id`.
+        let mut data_key = Vec::new();
+        if property == "data" {
+            while input.peek(Token![-]) {
+                input.parse::<Token![-]>()?;
+                data_key.push(input.call(Ident::parse_any)?.to_string());
+            }
+        }
         input.parse::<Token![=]>()?;
-        let value = input.parse::<LitStr>()?;
-        if property.to_string() == "class" {
-            properties.class = Some(value.value());
+        if !data_key.is_empty() {
+            let value = input.parse::<LitStr>()?.value();
+            properties.data.push((data_key.join("-"), value));
+        } else if property == "id" {
+            properties.id = Some(input.parse::<LitStr>()?.value());
+        } else if property == "name" {
+            properties.name = Some(input.parse::<LitStr>()?.value());
+        } else if property == "ref" {
+            let content;
+            braced!(content in input);
+            properties.reference = Some(content.parse::<Expr>()?);
+        } else if property == "components" {
+            let content;
+            braced!(content in input);
+            properties.components = Some(content.parse::<Expr>()?);
+        } else if property == "class" {
+            properties.class = Some(input.parse::<LitStr>()?.value());
+        } else if property == "style" {
+            let content;
+            braced!(content in input);
+            properties.style = Some(content.parse::<Expr>()?);
         } else {
             return Err(syn::Error::new(property.span(), "Unsupported property"));
         }
@@ -76,7 +111,7 @@ fn parse_div_tag(input: &ParseStream, opening_tag: Ident) -> Result<FauxNode, sy
     input.parse::<Token![/]>()?;
     let closing_tag = input.parse::<Ident>()?;
 
-    if closing_tag.to_string() != opening_tag.to_string() {
+    if closing_tag != opening_tag {
         return Err(syn::Error::new(
             closing_tag.span(),
             "Mismatched closing tag",
@@ -84,7 +119,7 @@ fn parse_div_tag(input: &ParseStream, opening_tag: Ident) -> Result<FauxNode, sy
     }
 
     input.parse::<Token![>]>()?;
-    Ok(FauxNode::Div(content.nodes, properties))
+    Ok(FauxNode::Div(content.nodes, Box::new(properties)))
 }
 
 #[proc_macro]
@@ -1,27 +1,74 @@
 use quote::{quote, ToTokens, TokenStreamExt};
-use syn::ExprBlock;
+use syn::{Expr, ExprBlock};
 
+/// The attributes parsed from an `rsx!` element, which expand into `faux_dom::Properties`.
 pub struct Properties {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub reference: Option<Expr>,
+    pub components: Option<Expr>,
     pub class: Option<String>,
+    pub style: Option<Expr>,
+    /// `data-*` attributes, keyed without the `data-` prefix.
+    pub data: Vec<(String, String)>,
 }
 
+/// An `rsx!` tree as parsed by the macro, which expands into a `faux_dom::FauxNode`.
 pub enum FauxNode {
     Text(String),
-    Div(Vec<FauxNode>, Properties),
+    Div(Vec<FauxNode>, Box<Properties>),
     Expr(ExprBlock),
     Fragment(Vec<FauxNode>),
 }
 
 impl ToTokens for Properties {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let id_tokens = match &self.id {
+            Some(id) => quote! { Some(#id.to_string()) },
+            None => quote! { None },
+        };
+        let name_tokens = match &self.name {
+            Some(name) => quote! { Some(#name.to_string()) },
+            None => quote! { None },
+        };
+        let reference_tokens = match &self.reference {
+            Some(reference) => quote! { Some(::std::clone::Clone::clone(&#reference)) },
+            None => quote! { None },
+        };
+        let components_tokens = match &self.components {
+            Some(components) => quote! { Some(::std::convert::Into::into(#components)) },
+            None => quote! { None },
+        };
         let class_tokens = match &self.class {
             Some(class) => quote! { Some(#class.to_string()) },
             None => quote! { None },
         };
+        let style_tokens = match &self.style {
+            Some(style) => {
+                quote! { Some(::std::boxed::Box::new(::std::convert::Into::into(#style))) }
+            }
+            None => quote! { None },
+        };
+
+        let data_tokens = if self.data.is_empty() {
+            quote! { ::std::collections::HashMap::new() }
+        } else {
+            let entries = self
+                .data
+                .iter()
+                .map(|(key, value)| quote! { (#key.to_string(), #value.to_string()) });
+            quote! { ::std::collections::HashMap::from([#(#entries),*]) }
+        };
 
         tokens.extend(quote! {
             Properties {
+                id: #id_tokens,
+                name: #name_tokens,
+                reference: #reference_tokens,
+                components: #components_tokens,
                 class: #class_tokens,
+                style: #style_tokens,
+                data: #data_tokens,
             }
         });
     }
@@ -58,8 +105,22 @@ impl ToTokens for FauxNode {
     }
 }
 
-#[derive(Debug, thiserror::Error)]
+/// Why a class could not be resolved, or a tree could not be rendered. A stylesheet either fails
+/// with one outright or skips the class and reports it in its warnings, while a render fails on
+/// the first one it hits, before spawning anything.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
 pub enum Error {
-    #[error("TryFrom failed: {0}")]
-    TryFrom(String),
+    #[error("unknown utility `{0}`")]
+    UnknownUtility(String),
+    #[error("malformed value in `{0}`")]
+    MalformedValue(String),
+    #[error("unsupported variant `{variant}:` in `{class}`")]
+    UnsupportedVariant { variant: String, class: String },
+    #[error("`{class}` overrides `{previous}`")]
+    Conflict { class: String, previous: String },
+    #[error("alias cycle: {0}")]
+    AliasCycle(String),
+    /// Only returned by render calls, never by a stylesheet.
+    #[error("id `{0}` is used by more than one element")]
+    DuplicateId(String),
 }
@@ -1,19 +1,22 @@
-use std::path::Path;
+use std::collections::HashMap;
 
-use bevy::prelude::*;
-use faux_dom::{render, FauxNode, FauxStyle, Properties, Stylesheet};
+use bevy::{prelude::*, text::BreakLineOn};
+use faux_dom::{
+    render, Error, FauxDomPlugin, FauxNode, FauxRect, FauxStyle, FontWeight, Fonts, Properties,
+    Styles, Stylesheet,
+};
 use faux_dom_macro::rsx;
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, FauxDomPlugin))
         .add_systems(Startup, setup)
         .run();
 }
 
 fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn(Camera2dBundle::default());
-    let font = asset_server.load::<Font>("roboto.ttf");
+    let fonts = Fonts::new(asset_server.load::<Font>("roboto.ttf"));
 
     let root = commands
         .spawn(NodeBundle {
@@ -26,17 +29,19 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         })
         .id();
 
-    let apple = "banana";
-
-    render(
+    let tree = render(
         rsx! {<div class="flex bg-black text-4xl w-64 px-4 py-2">
             "hello"
-        <div>{if 1 == 0 { rsx!{"world"} } else { rsx!{"sworld"} }}</div></div>},
+        <div id="world" style={Style { width: Val::Percent(50.0), ..default() }}>
+            {if 1 == 0 { rsx!{"world"} } else { rsx!{"sworld"} }}
+        </div></div>},
         &mut commands,
         root,
-        &font,
+        &fonts,
         &DefaultStyleSheet { rem: REM },
-    );
+    )
+    .expect("the default stylesheet only warns");
+    commands.entity(root).insert(tree.ids);
 }
 
 const ASPECT_VIDEO: f32 = 16.0 / 9.0;
@@ -49,559 +54,1762 @@ struct DefaultStyleSheet {
 const REM: f32 = 16.0;
 
 impl Stylesheet for DefaultStyleSheet {
-    fn get_styles(&self, names: &str) -> FauxStyle {
-        let mut style = Style {
-            ..Default::default()
-        };
+    fn get_styles(&self, names: &str) -> Result<Styles, Error> {
+        let mut style = FauxStyle::default();
+        let mut warnings = Vec::new();
+        // The class that last set each field, so `p-4 p-2` and `p-4 px-2` can be reported.
+        let mut set_by = HashMap::new();
 
-        let mut background_color = None;
-        let mut color = None;
-        let mut font_size = None;
-
-        for name in names.split(" ") {
-            match name {
-                "block" => style.display = Display::None,
-                "grid" => style.display = Display::Grid,
-                "flex" => style.display = Display::Flex,
-                "flex-row" => style.flex_direction = FlexDirection::Row,
-                "flex-col" => style.flex_direction = FlexDirection::Column,
-                "flex-row-reverse" => style.flex_direction = FlexDirection::RowReverse,
-                "flex-col-reverse" => style.flex_direction = FlexDirection::ColumnReverse,
-                "items-start" => style.align_content = AlignContent::FlexStart,
-                "items-end" => style.align_content = AlignContent::FlexEnd,
-                "items-center" => style.align_content = AlignContent::Center,
-                "items-between" => style.align_content = AlignContent::SpaceBetween,
-                "items-around" => style.align_content = AlignContent::SpaceAround,
-                "items-evenly" => style.align_content = AlignContent::SpaceEvenly,
-                "items-stretch" => style.align_content = AlignContent::Stretch,
-                "self-start" => style.align_self = AlignSelf::FlexStart,
-                "self-end" => style.align_self = AlignSelf::FlexEnd,
-                "self-center" => style.align_self = AlignSelf::Center,
-                "self-stretch" => style.align_self = AlignSelf::Stretch,
-                "self-auto" => style.align_self = AlignSelf::Auto,
-                "self-baseline" => style.align_self = AlignSelf::Baseline,
-                "justify-normal" => style.justify_content = JustifyContent::Default,
-                "justify-start" => style.justify_content = JustifyContent::FlexStart,
-                "justify-end" => style.justify_content = JustifyContent::FlexEnd,
-                "justify-center" => style.justify_content = JustifyContent::Center,
-                "justify-between" => style.justify_content = JustifyContent::SpaceBetween,
-                "justify-around" => style.justify_content = JustifyContent::SpaceAround,
-                "justify-evenly" => style.justify_content = JustifyContent::SpaceEvenly,
-                "justify-stretch" => style.justify_content = JustifyContent::Stretch,
-                "aspect-auto" => style.aspect_ratio = None,
-                "aspect-square" => style.aspect_ratio = Some(ASPECT_SQUARE),
-                "aspect-video" => style.aspect_ratio = Some(ASPECT_VIDEO),
-                "grow" => style.flex_grow = 1.0,
-                "grow-0" => style.flex_grow = 0.0,
-                "shrink" => style.flex_shrink = 1.0,
-                "shrink-0" => style.flex_shrink = 0.0,
-                "w-0" | "w-px" | "w-0.5" | "w-1" | "w-1.5" | "w-2" | "w-2.5" | "w-3" | "w-3.5"
-                | "w-4" | "w-5" | "w-6" | "w-7" | "w-8" | "w-9" | "w-10" | "w-11" | "w-12"
-                | "w-14" | "w-16" | "w-20" | "w-24" | "w-28" | "w-32" | "w-36" | "w-40"
-                | "w-44" | "w-48" | "w-52" | "w-56" | "w-60" | "w-64" | "w-72" | "w-80"
-                | "w-96" => {
-                    let value = name["w-".len()..].parse::<f32>().unwrap_or(1.0);
-                    style.width = Val::Px(value * self.rem / 4.0)
-                }
-                "w-1/2" | "w-1/3" | "w-2/3" | "w-1/4" | "w-2/4" | "w-3/4" | "w-1/5" | "w-2/5"
-                | "w-3/5" | "w-4/5" | "w-1/6" | "w-2/6" | "w-3/6" | "w-4/6" | "w-5/6"
-                | "w-1/12" | "w-2/12" | "w-3/12" | "w-4/12" | "w-5/12" | "w-6/12" | "w-7/12"
-                | "w-8/12" | "w-9/12" | "w-10/12" | "w-11/12" => {
-                    if let Some(value) = parse_ratio(&name["w-".len()..]) {
-                        style.width = value
-                    }
-                }
-                "w-full" => style.width = Val::Percent(100.0),
-                "w-screen" => style.width = Val::Vw(100.0),
-                "h-0" | "h-px" | "h-0.5" | "h-1" | "h-1.5" | "h-2" | "h-2.5" | "h-3" | "h-3.5"
-                | "h-4" | "h-5" | "h-6" | "h-7" | "h-8" | "h-9" | "h-10" | "h-11" | "h-12"
-                | "h-14" | "h-16" | "h-20" | "h-24" | "h-28" | "h-32" | "h-36" | "h-40"
-                | "h-44" | "h-48" | "h-52" | "h-56" | "h-60" | "h-64" | "h-72" | "h-80"
-                | "h-96" => {
-                    if let Some(value) = name["h-".len()..].parse::<f32>().ok() {
-                        style.height = Val::Px(value * self.rem / 4.0);
-                    }
-                }
-                "h-1/2" | "h-1/3" | "h-2/3" | "h-1/4" | "h-2/4" | "h-3/4" | "h-1/5" | "h-2/5"
-                | "h-3/5" | "h-4/5" | "h-1/6" | "h-2/6" | "h-3/6" | "h-4/6" | "h-5/6"
-                | "h-1/12" | "h-2/12" | "h-3/12" | "h-4/12" | "h-5/12" | "h-6/12" | "h-7/12"
-                | "h-8/12" | "h-9/12" | "h-10/12" | "h-11/12" => {
-                    if let Some(value) = parse_ratio(&name["h-".len()..]) {
-                        style.height = value;
-                    }
-                }
-                "h-full" => style.height = Val::Percent(100.0),
-                "h-screen" => style.height = Val::Vh(100.0),
-                width if width.starts_with("w-[") && width.ends_with("]") => {
-                    let interpolated_value = &width["w-[".len()..width.len() - 1];
-                    if let Some(val) = parse_size(interpolated_value) {
-                        style.width = val;
-                    }
-                }
-                height if height.starts_with("h-[") && height.ends_with("]") => {
-                    let interpolated_value = &height["h-[".len()..height.len() - 1];
-                    if let Some(val) = parse_size(interpolated_value) {
-                        style.height = val;
+        for name in names.split_whitespace() {
+            match self.apply(name, &mut style) {
+                Ok(property) => {
+                    let fields = fields(property).unwrap_or(std::slice::from_ref(&property));
+                    let mut previous = Vec::new();
+                    for field in fields {
+                        if let Some(class) = set_by.insert(*field, name) {
+                            if !previous.contains(&class) {
+                                previous.push(class);
+                            }
+                        }
                     }
+                    warnings.extend(previous.into_iter().map(|previous| Error::Conflict {
+                        class: name.to_string(),
+                        previous: previous.to_string(),
+                    }));
                 }
-                "border" | "border-0" | "border-2" | "border-4" | "border-8" => {
-                    let value = name["border-".len()..].parse::<f32>().unwrap_or(1.0);
-                    style.border = UiRect::all(Val::Px(value))
-                }
-                "border-t" | "border-t-0" | "border-t-2" | "border-t-4" | "border-t-8" => {
-                    let value = name["border-t-".len()..].parse::<f32>().unwrap_or(1.0);
-                    style.border.top = Val::Px(value)
-                }
-                "border-r" | "border-r-0" | "border-r-2" | "border-r-4" | "border-r-8" => {
-                    let value = name["border-r-".len()..].parse::<f32>().unwrap_or(1.0);
-                    style.border.right = Val::Px(value)
-                }
-                "border-b" | "border-b-0" | "border-b-2" | "border-b-4" | "border-b-8" => {
-                    let value = name["border-b-".len()..].parse::<f32>().unwrap_or(1.0);
-                    style.border.bottom = Val::Px(value)
-                }
-                "border-l" | "border-l-0" | "border-l-2" | "border-l-4" | "border-l-8" => {
-                    let value = name["border-l-".len()..].parse::<f32>().unwrap_or(1.0);
-                    style.border.left = Val::Px(value)
-                }
-                "p-0" | "p-px" | "p-0.5" | "p-1" | "p-1.5" | "p-2" | "p-2.5" | "p-3" | "p-3.5"
-                | "p-4" | "p-5" | "p-6" | "p-7" | "p-8" | "p-9" | "p-10" | "p-11" | "p-12"
-                | "p-14" | "p-16" | "p-20" | "p-24" | "p-28" | "p-32" | "p-36" | "p-40"
-                | "p-44" | "p-48" | "p-52" | "p-56" | "p-60" | "p-64" | "p-72" | "p-80"
-                | "p-96" => {
-                    let value = name["p-".len()..].parse::<f32>();
-                    style.padding =
-                        UiRect::all(Val::Px(value.map(|v| v * self.rem / 4.0).unwrap_or(1.0)))
-                }
-                "pt-0" | "pt-px" | "pt-0.5" | "pt-1" | "pt-1.5" | "pt-2" | "pt-2.5" | "pt-3"
-                | "pt-3.5" | "pt-4" | "pt-5" | "pt-6" | "pt-7" | "pt-8" | "pt-9" | "pt-10"
-                | "pt-11" | "pt-12" | "pt-14" | "pt-16" | "pt-20" | "pt-24" | "pt-28" | "pt-32"
-                | "pt-36" | "pt-40" | "pt-44" | "pt-48" | "pt-52" | "pt-56" | "pt-60" | "pt-64"
-                | "pt-72" | "pt-80" | "pt-96" => {
-                    let value = name["pt-".len()..].parse::<f32>();
-                    style.padding.top = Val::Px(value.map(|v| v * self.rem / 4.0).unwrap_or(1.0))
-                }
-                "pr-0" | "pr-px" | "pr-0.5" | "pr-1" | "pr-1.5" | "pr-2" | "pr-2.5" | "pr-3"
-                | "pr-3.5" | "pr-4" | "pr-5" | "pr-6" | "pr-7" | "pr-8" | "pr-9" | "pr-10"
-                | "pr-11" | "pr-12" | "pr-14" | "pr-16" | "pr-20" | "pr-24" | "pr-28" | "pr-32"
-                | "pr-36" | "pr-40" | "pr-44" | "pr-48" | "pr-52" | "pr-56" | "pr-60" | "pr-64"
-                | "pr-72" | "pr-80" | "pr-96" => {
-                    let value = name["pr-".len()..].parse::<f32>();
-                    style.padding.right = Val::Px(value.map(|v| v * self.rem / 4.0).unwrap_or(1.0))
-                }
-                "pb-0" | "pb-px" | "pb-0.5" | "pb-1" | "pb-1.5" | "pb-2" | "pb-2.5" | "pb-3"
-                | "pb-3.5" | "pb-4" | "pb-5" | "pb-6" | "pb-7" | "pb-8" | "pb-9" | "pb-10"
-                | "pb-11" | "pb-12" | "pb-14" | "pb-16" | "pb-20" | "pb-24" | "pb-28" | "pb-32"
-                | "pb-36" | "pb-40" | "pb-44" | "pb-48" | "pb-52" | "pb-56" | "pb-60" | "pb-64"
-                | "pb-72" | "pb-80" | "pb-96" => {
-                    let value = name["pb-".len()..].parse::<f32>();
-                    style.padding.bottom = Val::Px(value.map(|v| v * self.rem / 4.0).unwrap_or(1.0))
-                }
-                "pl-0" | "pl-px" | "pl-0.5" | "pl-1" | "pl-1.5" | "pl-2" | "pl-2.5" | "pl-3"
-                | "pl-3.5" | "pl-4" | "pl-5" | "pl-6" | "pl-7" | "pl-8" | "pl-9" | "pl-10"
-                | "pl-11" | "pl-12" | "pl-14" | "pl-16" | "pl-20" | "pl-24" | "pl-28" | "pl-32"
-                | "pl-36" | "pl-40" | "pl-44" | "pl-48" | "pl-52" | "pl-56" | "pl-60" | "pl-64"
-                | "pl-72" | "pl-80" | "pl-96" => {
-                    let value = name["pl-".len()..].parse::<f32>();
-                    style.padding.left = Val::Px(value.map(|v| v * self.rem / 4.0).unwrap_or(1.0))
-                }
-                "px-0" | "px-px" | "px-0.5" | "px-1" | "px-1.5" | "px-2" | "px-2.5" | "px-3"
-                | "px-3.5" | "px-4" | "px-5" | "px-6" | "px-7" | "px-8" | "px-9" | "px-10"
-                | "px-11" | "px-12" | "px-14" | "px-16" | "px-20" | "px-24" | "px-28" | "px-32"
-                | "px-36" | "px-40" | "px-44" | "px-48" | "px-52" | "px-56" | "px-60" | "px-64"
-                | "px-72" | "px-80" | "px-96" => {
-                    let number = name["px-".len()..].parse::<f32>();
-                    let value = Val::Px(number.map(|v| v * self.rem / 4.0).unwrap_or(1.0));
-                    style.padding.left = value;
-                    style.padding.right = value;
-                }
-                "py-0" | "py-px" | "py-0.5" | "py-1" | "py-1.5" | "py-2" | "py-2.5" | "py-3"
-                | "py-3.5" | "py-4" | "py-5" | "py-6" | "py-7" | "py-8" | "py-9" | "py-10"
-                | "py-11" | "py-12" | "py-14" | "py-16" | "py-20" | "py-24" | "py-28" | "py-32"
-                | "py-36" | "py-40" | "py-44" | "py-48" | "py-52" | "py-56" | "py-60" | "py-64"
-                | "py-72" | "py-80" | "py-96" => {
-                    let number = name["py-".len()..].parse::<f32>();
-                    let value = Val::Px(number.map(|v| v * self.rem / 4.0).unwrap_or(1.0));
-                    style.padding.top = value;
-                    style.padding.bottom = value;
-                }
-                "m-0" | "m-px" | "m-0.5" | "m-1" | "m-1.5" | "m-2" | "m-2.5" | "m-3" | "m-3.5"
-                | "m-4" | "m-5" | "m-6" | "m-7" | "m-8" | "m-9" | "m-10" | "m-11" | "m-12"
-                | "m-14" | "m-16" | "m-20" | "m-24" | "m-28" | "m-32" | "m-36" | "m-40"
-                | "m-44" | "m-48" | "m-52" | "m-56" | "m-60" | "m-64" | "m-72" | "m-80"
-                | "m-96" => {
-                    let value = name["m-".len()..].parse::<f32>();
-                    style.margin =
-                        UiRect::all(Val::Px(value.map(|v| v * self.rem / 4.0).unwrap_or(1.0)))
-                }
-                "mt-0" | "mt-px" | "mt-0.5" | "mt-1" | "mt-1.5" | "mt-2" | "mt-2.5" | "mt-3"
-                | "mt-3.5" | "mt-4" | "mt-5" | "mt-6" | "mt-7" | "mt-8" | "mt-9" | "mt-10"
-                | "mt-11" | "mt-12" | "mt-14" | "mt-16" | "mt-20" | "mt-24" | "mt-28" | "mt-32"
-                | "mt-36" | "mt-40" | "mt-44" | "mt-48" | "mt-52" | "mt-56" | "mt-60" | "mt-64"
-                | "mt-72" | "mt-80" | "mt-96" => {
-                    let value = name["mt-".len()..].parse::<f32>();
-                    style.margin.top = Val::Px(value.map(|v| v * self.rem / 4.0).unwrap_or(1.0))
-                }
-                "mr-0" | "mr-px" | "mr-0.5" | "mr-1" | "mr-1.5" | "mr-2" | "mr-2.5" | "mr-3"
-                | "mr-3.5" | "mr-4" | "mr-5" | "mr-6" | "mr-7" | "mr-8" | "mr-9" | "mr-10"
-                | "mr-11" | "mr-12" | "mr-14" | "mr-16" | "mr-20" | "mr-24" | "mr-28" | "mr-32"
-                | "mr-36" | "mr-40" | "mr-44" | "mr-48" | "mr-52" | "mr-56" | "mr-60" | "mr-64"
-                | "mr-72" | "mr-80" | "mr-96" => {
-                    let value = name["mr-".len()..].parse::<f32>();
-                    style.margin.right = Val::Px(value.map(|v| v * self.rem / 4.0).unwrap_or(1.0))
-                }
-                "mb-0" | "mb-px" | "mb-0.5" | "mb-1" | "mb-1.5" | "mb-2" | "mb-2.5" | "mb-3"
-                | "mb-3.5" | "mb-4" | "mb-5" | "mb-6" | "mb-7" | "mb-8" | "mb-9" | "mb-10"
-                | "mb-11" | "mb-12" | "mb-14" | "mb-16" | "mb-20" | "mb-24" | "mb-28" | "mb-32"
-                | "mb-36" | "mb-40" | "mb-44" | "mb-48" | "mb-52" | "mb-56" | "mb-60" | "mb-64"
-                | "mb-72" | "mb-80" | "mb-96" => {
-                    let value = name["mb-".len()..].parse::<f32>();
-                    style.margin.bottom = Val::Px(value.map(|v| v * self.rem / 4.0).unwrap_or(1.0))
-                }
-                "ml-0" | "ml-px" | "ml-0.5" | "ml-1" | "ml-1.5" | "ml-2" | "ml-2.5" | "ml-3"
-                | "ml-3.5" | "ml-4" | "ml-5" | "ml-6" | "ml-7" | "ml-8" | "ml-9" | "ml-10"
-                | "ml-11" | "ml-12" | "ml-14" | "ml-16" | "ml-20" | "ml-24" | "ml-28" | "ml-32"
-                | "ml-36" | "ml-40" | "ml-44" | "ml-48" | "ml-52" | "ml-56" | "ml-60" | "ml-64"
-                | "ml-72" | "ml-80" | "ml-96" => {
-                    let value = name["ml-".len()..].parse::<f32>();
-                    style.margin.left = Val::Px(value.map(|v| v * self.rem / 4.0).unwrap_or(1.0))
-                }
-                "mx-0" | "mx-px" | "mx-0.5" | "mx-1" | "mx-1.5" | "mx-2" | "mx-2.5" | "mx-3"
-                | "mx-3.5" | "mx-4" | "mx-5" | "mx-6" | "mx-7" | "mx-8" | "mx-9" | "mx-10"
-                | "mx-11" | "mx-12" | "mx-14" | "mx-16" | "mx-20" | "mx-24" | "mx-28" | "mx-32"
-                | "mx-36" | "mx-40" | "mx-44" | "mx-48" | "mx-52" | "mx-56" | "mx-60" | "mx-64"
-                | "mx-72" | "mx-80" | "mx-96" => {
-                    let number = name["mx-".len()..].parse::<f32>();
-                    let value = Val::Px(number.map(|v| v * self.rem / 4.0).unwrap_or(1.0));
-                    style.margin.left = value;
-                    style.margin.right = value;
-                }
-                "my-0" | "my-px" | "my-0.5" | "my-1" | "my-1.5" | "my-2" | "my-2.5" | "my-3"
-                | "my-3.5" | "my-4" | "my-5" | "my-6" | "my-7" | "my-8" | "my-9" | "my-10"
-                | "my-11" | "my-12" | "my-14" | "my-16" | "my-20" | "my-24" | "my-28" | "my-32"
-                | "my-36" | "my-40" | "my-44" | "my-48" | "my-52" | "my-56" | "my-60" | "my-64"
-                | "my-72" | "my-80" | "my-96" => {
-                    let number = name["my-".len()..].parse::<f32>();
-                    let value = Val::Px(number.map(|v| v * self.rem / 4.0).unwrap_or(1.0));
-                    style.margin.top = value;
-                    style.margin.bottom = value;
+                Err(warning) => warnings.push(warning),
+            }
+        }
+
+        Ok(Styles { style, warnings })
+    }
+}
+
+/// The fields set by a shorthand property returned from [`DefaultStyleSheet::apply`], or `None`
+/// if the property is a single field.
+fn fields(property: &str) -> Option<&'static [&'static str]> {
+    Some(match property {
+        "padding" => &[
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+        "padding-x" => &["padding-right", "padding-left"],
+        "padding-y" => &["padding-top", "padding-bottom"],
+        "margin" => &["margin-top", "margin-right", "margin-bottom", "margin-left"],
+        "margin-x" => &["margin-right", "margin-left"],
+        "margin-y" => &["margin-top", "margin-bottom"],
+        "border-width" => &[
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+        "size" => &["width", "height"],
+        "gap" => &["row-gap", "column-gap"],
+        "overflow" => &["overflow-x", "overflow-y"],
+        "truncate" => &["overflow-x", "overflow-y", "line-break"],
+        "grid-column" => &["grid-column-start", "grid-column-end", "grid-column-span"],
+        "grid-row" => &["grid-row-start", "grid-row-end", "grid-row-span"],
+        _ => return None,
+    })
+}
+
+impl DefaultStyleSheet {
+    /// Applies a single utility class and returns the property it sets, which may be a shorthand
+    /// for several [`fields`].
+    fn apply(&self, name: &str, styles: &mut FauxStyle) -> Result<&'static str, Error> {
+        let invalid = || Error::MalformedValue(name.to_string());
+        // Variants like `hover:` or `md:` need state the stylesheet doesn't have.
+        if let Some((variant, _)) = name.split_once(':').filter(|(v, _)| !v.contains('[')) {
+            return Err(Error::UnsupportedVariant {
+                variant: variant.to_string(),
+                class: name.to_string(),
+            });
+        }
+
+        let property = match name {
+            // Bevy has no block layout, a flex container is the closest match.
+            "block" => {
+                styles.display = Some(Display::Flex);
+                "display"
+            }
+            "hidden" => {
+                styles.display = Some(Display::None);
+                "display"
+            }
+            "visible" => {
+                styles.visibility = Some(Visibility::Visible);
+                "visibility"
+            }
+            "invisible" => {
+                styles.visibility = Some(Visibility::Hidden);
+                "visibility"
+            }
+            "opacity-0" | "opacity-5" | "opacity-10" | "opacity-15" | "opacity-20"
+            | "opacity-25" | "opacity-30" | "opacity-35" | "opacity-40" | "opacity-45"
+            | "opacity-50" | "opacity-55" | "opacity-60" | "opacity-65" | "opacity-70"
+            | "opacity-75" | "opacity-80" | "opacity-85" | "opacity-90" | "opacity-95"
+            | "opacity-100" => {
+                let value = parse_number(&name["opacity-".len()..]).ok_or_else(invalid)?;
+                styles.opacity = Some(value / 100.0);
+                "opacity"
+            }
+            value if value.starts_with("opacity-[") && value.ends_with("]") => {
+                let interpolated_value = &value["opacity-[".len()..value.len() - 1];
+                let value = parse_number(interpolated_value).ok_or_else(invalid)?;
+                styles.opacity = Some(value.clamp(0.0, 1.0));
+                "opacity"
+            }
+            "grid" => {
+                styles.display = Some(Display::Grid);
+                "display"
+            }
+            "flex" => {
+                styles.display = Some(Display::Flex);
+                "display"
+            }
+            "flex-row" => {
+                styles.flex_direction = Some(FlexDirection::Row);
+                "flex-direction"
+            }
+            "flex-col" => {
+                styles.flex_direction = Some(FlexDirection::Column);
+                "flex-direction"
+            }
+            "flex-row-reverse" => {
+                styles.flex_direction = Some(FlexDirection::RowReverse);
+                "flex-direction"
+            }
+            "flex-col-reverse" => {
+                styles.flex_direction = Some(FlexDirection::ColumnReverse);
+                "flex-direction"
+            }
+            "items-start" => {
+                styles.align_content = Some(AlignContent::FlexStart);
+                "align-content"
+            }
+            "items-end" => {
+                styles.align_content = Some(AlignContent::FlexEnd);
+                "align-content"
+            }
+            "items-center" => {
+                styles.align_content = Some(AlignContent::Center);
+                "align-content"
+            }
+            "items-between" => {
+                styles.align_content = Some(AlignContent::SpaceBetween);
+                "align-content"
+            }
+            "items-around" => {
+                styles.align_content = Some(AlignContent::SpaceAround);
+                "align-content"
+            }
+            "items-evenly" => {
+                styles.align_content = Some(AlignContent::SpaceEvenly);
+                "align-content"
+            }
+            "items-stretch" => {
+                styles.align_content = Some(AlignContent::Stretch);
+                "align-content"
+            }
+            "self-start" => {
+                styles.align_self = Some(AlignSelf::FlexStart);
+                "align-self"
+            }
+            "self-end" => {
+                styles.align_self = Some(AlignSelf::FlexEnd);
+                "align-self"
+            }
+            "self-center" => {
+                styles.align_self = Some(AlignSelf::Center);
+                "align-self"
+            }
+            "self-stretch" => {
+                styles.align_self = Some(AlignSelf::Stretch);
+                "align-self"
+            }
+            "self-auto" => {
+                styles.align_self = Some(AlignSelf::Auto);
+                "align-self"
+            }
+            "self-baseline" => {
+                styles.align_self = Some(AlignSelf::Baseline);
+                "align-self"
+            }
+            "justify-normal" => {
+                styles.justify_content = Some(JustifyContent::Default);
+                "justify-content"
+            }
+            "justify-start" => {
+                styles.justify_content = Some(JustifyContent::FlexStart);
+                "justify-content"
+            }
+            "justify-end" => {
+                styles.justify_content = Some(JustifyContent::FlexEnd);
+                "justify-content"
+            }
+            "justify-center" => {
+                styles.justify_content = Some(JustifyContent::Center);
+                "justify-content"
+            }
+            "justify-between" => {
+                styles.justify_content = Some(JustifyContent::SpaceBetween);
+                "justify-content"
+            }
+            "justify-around" => {
+                styles.justify_content = Some(JustifyContent::SpaceAround);
+                "justify-content"
+            }
+            "justify-evenly" => {
+                styles.justify_content = Some(JustifyContent::SpaceEvenly);
+                "justify-content"
+            }
+            "justify-stretch" => {
+                styles.justify_content = Some(JustifyContent::Stretch);
+                "justify-content"
+            }
+            "aspect-auto" => {
+                styles.aspect_ratio = Some(None);
+                "aspect-ratio"
+            }
+            "aspect-square" => {
+                styles.aspect_ratio = Some(Some(ASPECT_SQUARE));
+                "aspect-ratio"
+            }
+            "aspect-video" => {
+                styles.aspect_ratio = Some(Some(ASPECT_VIDEO));
+                "aspect-ratio"
+            }
+            aspect if aspect.starts_with("aspect-[") && aspect.ends_with("]") => {
+                let interpolated_value = &aspect["aspect-[".len()..aspect.len() - 1];
+                styles.aspect_ratio = Some(Some(
+                    parse_aspect_ratio(interpolated_value).ok_or_else(invalid)?,
+                ));
+                "aspect-ratio"
+            }
+            "grid-cols-none" => {
+                styles.grid_template_columns = Some(vec![]);
+                "grid-template-columns"
+            }
+            "grid-cols-1" | "grid-cols-2" | "grid-cols-3" | "grid-cols-4" | "grid-cols-5"
+            | "grid-cols-6" | "grid-cols-7" | "grid-cols-8" | "grid-cols-9" | "grid-cols-10"
+            | "grid-cols-11" | "grid-cols-12" => {
+                let count = name["grid-cols-".len()..]
+                    .parse::<u16>()
+                    .map_err(|_| invalid())?;
+                styles.grid_template_columns = Some(RepeatedGridTrack::flex(count, 1.0));
+                "grid-template-columns"
+            }
+            "grid-rows-none" => {
+                styles.grid_template_rows = Some(vec![]);
+                "grid-template-rows"
+            }
+            "grid-rows-1" | "grid-rows-2" | "grid-rows-3" | "grid-rows-4" | "grid-rows-5"
+            | "grid-rows-6" | "grid-rows-7" | "grid-rows-8" | "grid-rows-9" | "grid-rows-10"
+            | "grid-rows-11" | "grid-rows-12" => {
+                let count = name["grid-rows-".len()..]
+                    .parse::<u16>()
+                    .map_err(|_| invalid())?;
+                styles.grid_template_rows = Some(RepeatedGridTrack::flex(count, 1.0));
+                "grid-template-rows"
+            }
+            columns if columns.starts_with("grid-cols-[") && columns.ends_with("]") => {
+                let interpolated_value = &columns["grid-cols-[".len()..columns.len() - 1];
+                styles.grid_template_columns = Some(
+                    self.parse_grid_template(interpolated_value)
+                        .ok_or_else(invalid)?,
+                );
+                "grid-template-columns"
+            }
+            rows if rows.starts_with("grid-rows-[") && rows.ends_with("]") => {
+                let interpolated_value = &rows["grid-rows-[".len()..rows.len() - 1];
+                styles.grid_template_rows = Some(
+                    self.parse_grid_template(interpolated_value)
+                        .ok_or_else(invalid)?,
+                );
+                "grid-template-rows"
+            }
+            "grid-flow-row" => {
+                styles.grid_auto_flow = Some(GridAutoFlow::Row);
+                "grid-auto-flow"
+            }
+            "grid-flow-col" => {
+                styles.grid_auto_flow = Some(GridAutoFlow::Column);
+                "grid-auto-flow"
+            }
+            "grid-flow-dense" | "grid-flow-row-dense" => {
+                styles.grid_auto_flow = Some(GridAutoFlow::RowDense);
+                "grid-auto-flow"
+            }
+            "grid-flow-col-dense" => {
+                styles.grid_auto_flow = Some(GridAutoFlow::ColumnDense);
+                "grid-auto-flow"
+            }
+            "auto-cols-auto" => {
+                styles.grid_auto_columns = Some(GridTrack::auto());
+                "grid-auto-columns"
+            }
+            "auto-cols-min" => {
+                styles.grid_auto_columns = Some(GridTrack::min_content());
+                "grid-auto-columns"
+            }
+            "auto-cols-max" => {
+                styles.grid_auto_columns = Some(GridTrack::max_content());
+                "grid-auto-columns"
+            }
+            "auto-cols-fr" => {
+                styles.grid_auto_columns = Some(GridTrack::flex(1.0));
+                "grid-auto-columns"
+            }
+            "auto-rows-auto" => {
+                styles.grid_auto_rows = Some(GridTrack::auto());
+                "grid-auto-rows"
+            }
+            "auto-rows-min" => {
+                styles.grid_auto_rows = Some(GridTrack::min_content());
+                "grid-auto-rows"
+            }
+            "auto-rows-max" => {
+                styles.grid_auto_rows = Some(GridTrack::max_content());
+                "grid-auto-rows"
+            }
+            "auto-rows-fr" => {
+                styles.grid_auto_rows = Some(GridTrack::flex(1.0));
+                "grid-auto-rows"
+            }
+            "col-auto" => {
+                styles.grid_column = Some(GridPlacement::auto());
+                "grid-column"
+            }
+            "col-span-full" => {
+                styles.grid_column = Some(GridPlacement::start_end(1, -1));
+                "grid-column"
+            }
+            span if span.starts_with("col-span-") => {
+                let span = parse_grid_span(&span["col-span-".len()..]).ok_or_else(invalid)?;
+                styles.grid_column = Some(styles.grid_column.unwrap_or_default().set_span(span));
+                "grid-column-span"
+            }
+            "col-start-auto" => {
+                styles.grid_column = Some(clear_grid_start(styles.grid_column.unwrap_or_default()));
+                "grid-column-start"
+            }
+            start if start.starts_with("col-start-") => {
+                let line = parse_grid_line(&start["col-start-".len()..]).ok_or_else(invalid)?;
+                styles.grid_column = Some(styles.grid_column.unwrap_or_default().set_start(line));
+                "grid-column-start"
+            }
+            "col-end-auto" => {
+                styles.grid_column = Some(clear_grid_end(styles.grid_column.unwrap_or_default()));
+                "grid-column-end"
+            }
+            end if end.starts_with("col-end-") => {
+                let line = parse_grid_line(&end["col-end-".len()..]).ok_or_else(invalid)?;
+                styles.grid_column = Some(styles.grid_column.unwrap_or_default().set_end(line));
+                "grid-column-end"
+            }
+            "row-auto" => {
+                styles.grid_row = Some(GridPlacement::auto());
+                "grid-row"
+            }
+            "row-span-full" => {
+                styles.grid_row = Some(GridPlacement::start_end(1, -1));
+                "grid-row"
+            }
+            span if span.starts_with("row-span-") => {
+                let span = parse_grid_span(&span["row-span-".len()..]).ok_or_else(invalid)?;
+                styles.grid_row = Some(styles.grid_row.unwrap_or_default().set_span(span));
+                "grid-row-span"
+            }
+            "row-start-auto" => {
+                styles.grid_row = Some(clear_grid_start(styles.grid_row.unwrap_or_default()));
+                "grid-row-start"
+            }
+            start if start.starts_with("row-start-") => {
+                let line = parse_grid_line(&start["row-start-".len()..]).ok_or_else(invalid)?;
+                styles.grid_row = Some(styles.grid_row.unwrap_or_default().set_start(line));
+                "grid-row-start"
+            }
+            "row-end-auto" => {
+                styles.grid_row = Some(clear_grid_end(styles.grid_row.unwrap_or_default()));
+                "grid-row-end"
+            }
+            end if end.starts_with("row-end-") => {
+                let line = parse_grid_line(&end["row-end-".len()..]).ok_or_else(invalid)?;
+                styles.grid_row = Some(styles.grid_row.unwrap_or_default().set_end(line));
+                "grid-row-end"
+            }
+            gap if gap.starts_with("gap-x-") => {
+                styles.column_gap = Some(
+                    self.parse_spacing(&gap["gap-x-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "column-gap"
+            }
+            gap if gap.starts_with("gap-y-") => {
+                styles.row_gap = Some(
+                    self.parse_spacing(&gap["gap-y-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "row-gap"
+            }
+            gap if gap.starts_with("gap-") => {
+                let value = self
+                    .parse_spacing(&gap["gap-".len()..])
+                    .ok_or_else(invalid)?;
+                styles.column_gap = Some(value);
+                styles.row_gap = Some(value);
+                "gap"
+            }
+            z if z.starts_with("z-") || z.starts_with("-z-") => {
+                styles.z_index = Some(parse_z_index(z).ok_or_else(invalid)?);
+                "z-index"
+            }
+            "overflow-visible" => {
+                styles.overflow_x = Some(OverflowAxis::Visible);
+                styles.overflow_y = Some(OverflowAxis::Visible);
+                styles.scroll_y = Some(false);
+                "overflow"
+            }
+            "overflow-hidden" | "overflow-clip" => {
+                styles.overflow_x = Some(OverflowAxis::Clip);
+                styles.overflow_y = Some(OverflowAxis::Clip);
+                styles.scroll_y = Some(false);
+                "overflow"
+            }
+            "overflow-x-visible" => {
+                styles.overflow_x = Some(OverflowAxis::Visible);
+                "overflow-x"
+            }
+            "overflow-x-hidden" | "overflow-x-clip" => {
+                styles.overflow_x = Some(OverflowAxis::Clip);
+                "overflow-x"
+            }
+            "overflow-y-visible" => {
+                styles.overflow_y = Some(OverflowAxis::Visible);
+                styles.scroll_y = Some(false);
+                "overflow-y"
+            }
+            "overflow-y-hidden" | "overflow-y-clip" => {
+                styles.overflow_y = Some(OverflowAxis::Clip);
+                styles.scroll_y = Some(false);
+                "overflow-y"
+            }
+            "overflow-y-scroll" | "overflow-y-auto" => {
+                styles.scroll_y = Some(true);
+                "overflow-y"
+            }
+            "grow" => {
+                styles.flex_grow = Some(1.0);
+                "flex-grow"
+            }
+            "grow-0" => {
+                styles.flex_grow = Some(0.0);
+                "flex-grow"
+            }
+            "shrink" => {
+                styles.flex_shrink = Some(1.0);
+                "flex-shrink"
+            }
+            "shrink-0" => {
+                styles.flex_shrink = Some(0.0);
+                "flex-shrink"
+            }
+            grow if grow.starts_with("grow-[") => {
+                let grow = parse_number(unbracket(&grow["grow-".len()..])).ok_or_else(invalid)?;
+                styles.flex_grow = Some(grow);
+                "flex-grow"
+            }
+            shrink if shrink.starts_with("shrink-[") => {
+                let shrink =
+                    parse_number(unbracket(&shrink["shrink-".len()..])).ok_or_else(invalid)?;
+                styles.flex_shrink = Some(shrink);
+                "flex-shrink"
+            }
+            "w-screen" => {
+                styles.width = Some(Val::Vw(100.0));
+                "width"
+            }
+            width if width.starts_with("w-") => {
+                styles.width = Some(
+                    self.parse_length(&width["w-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "width"
+            }
+            "h-screen" => {
+                styles.height = Some(Val::Vh(100.0));
+                "height"
+            }
+            height if height.starts_with("h-") => {
+                styles.height = Some(
+                    self.parse_length(&height["h-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "height"
+            }
+            "min-w-screen" => {
+                styles.min_width = Some(Val::Vw(100.0));
+                "min-width"
+            }
+            min_width if min_width.starts_with("min-w-") => {
+                styles.min_width = Some(
+                    self.parse_length(&min_width["min-w-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "min-width"
+            }
+            "max-w-none" => {
+                styles.max_width = Some(Val::Auto);
+                "max-width"
+            }
+            "max-w-xs" | "max-w-sm" | "max-w-md" | "max-w-lg" | "max-w-xl" | "max-w-2xl"
+            | "max-w-3xl" | "max-w-4xl" | "max-w-5xl" | "max-w-6xl" | "max-w-7xl" => {
+                let rems = match name {
+                    "max-w-xs" => 20.0,
+                    "max-w-sm" => 24.0,
+                    "max-w-md" => 28.0,
+                    "max-w-lg" => 32.0,
+                    "max-w-xl" => 36.0,
+                    "max-w-2xl" => 42.0,
+                    "max-w-3xl" => 48.0,
+                    "max-w-4xl" => 56.0,
+                    "max-w-5xl" => 64.0,
+                    "max-w-6xl" => 72.0,
+                    _ => 80.0,
+                };
+                styles.max_width = Some(Val::Px(self.rem * rems));
+                "max-width"
+            }
+            "max-w-screen" => {
+                styles.max_width = Some(Val::Vw(100.0));
+                "max-width"
+            }
+            "max-w-screen-sm" | "max-w-screen-md" | "max-w-screen-lg" | "max-w-screen-xl"
+            | "max-w-screen-2xl" => {
+                let breakpoint = match name {
+                    "max-w-screen-sm" => 640.0,
+                    "max-w-screen-md" => 768.0,
+                    "max-w-screen-lg" => 1024.0,
+                    "max-w-screen-xl" => 1280.0,
+                    _ => 1536.0,
+                };
+                styles.max_width = Some(Val::Px(breakpoint));
+                "max-width"
+            }
+            max_width if max_width.starts_with("max-w-") => {
+                styles.max_width = Some(
+                    self.parse_length(&max_width["max-w-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "max-width"
+            }
+            "min-h-screen" => {
+                styles.min_height = Some(Val::Vh(100.0));
+                "min-height"
+            }
+            min_height if min_height.starts_with("min-h-") => {
+                styles.min_height = Some(
+                    self.parse_length(&min_height["min-h-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "min-height"
+            }
+            "max-h-none" => {
+                styles.max_height = Some(Val::Auto);
+                "max-height"
+            }
+            "max-h-screen" => {
+                styles.max_height = Some(Val::Vh(100.0));
+                "max-height"
+            }
+            max_height if max_height.starts_with("max-h-") => {
+                styles.max_height = Some(
+                    self.parse_length(&max_height["max-h-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "max-height"
+            }
+            size if size.starts_with("size-") => {
+                let val = self
+                    .parse_length(&size["size-".len()..])
+                    .ok_or_else(invalid)?;
+                styles.width = Some(val);
+                styles.height = Some(val);
+                "size"
+            }
+            "border" | "border-0" | "border-2" | "border-4" | "border-8" => {
+                styles.border = FauxRect::all(Val::Px(literal_width(name, "border-")));
+                "border-width"
+            }
+            "border-t" | "border-t-0" | "border-t-2" | "border-t-4" | "border-t-8" => {
+                styles.border.top = Some(Val::Px(literal_width(name, "border-t-")));
+                "border-top-width"
+            }
+            "border-r" | "border-r-0" | "border-r-2" | "border-r-4" | "border-r-8" => {
+                styles.border.right = Some(Val::Px(literal_width(name, "border-r-")));
+                "border-right-width"
+            }
+            "border-b" | "border-b-0" | "border-b-2" | "border-b-4" | "border-b-8" => {
+                styles.border.bottom = Some(Val::Px(literal_width(name, "border-b-")));
+                "border-bottom-width"
+            }
+            "border-l" | "border-l-0" | "border-l-2" | "border-l-4" | "border-l-8" => {
+                styles.border.left = Some(Val::Px(literal_width(name, "border-l-")));
+                "border-left-width"
+            }
+            border if border.starts_with("border-t-[") => {
+                styles.border.top = Some(
+                    self.parse_arbitrary_size(&border["border-t-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "border-top-width"
+            }
+            border if border.starts_with("border-r-[") => {
+                styles.border.right = Some(
+                    self.parse_arbitrary_size(&border["border-r-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "border-right-width"
+            }
+            border if border.starts_with("border-b-[") => {
+                styles.border.bottom = Some(
+                    self.parse_arbitrary_size(&border["border-b-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "border-bottom-width"
+            }
+            border if border.starts_with("border-l-[") => {
+                styles.border.left = Some(
+                    self.parse_arbitrary_size(&border["border-l-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "border-left-width"
+            }
+            "outline-none" => {
+                styles.outline_width = Some(Val::ZERO);
+                "outline-width"
+            }
+            "outline" | "outline-0" | "outline-1" | "outline-2" | "outline-4" | "outline-8" => {
+                styles.outline_width = Some(Val::Px(literal_width(name, "outline-")));
+                "outline-width"
+            }
+            "outline-offset-0" | "outline-offset-1" | "outline-offset-2" | "outline-offset-4"
+            | "outline-offset-8" => {
+                styles.outline_offset = Some(Val::Px(literal_width(name, "outline-offset-")));
+                "outline-offset"
+            }
+            offset if offset.starts_with("outline-offset-[") => {
+                styles.outline_offset = Some(
+                    self.parse_arbitrary_size(&offset["outline-offset-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "outline-offset"
+            }
+            // Arbitrary values are either a width, `outline-[3px]`, or a color.
+            outline_class if outline_class.starts_with("outline-") => {
+                let value = &outline_class["outline-".len()..];
+                if let Some(val) = self.parse_arbitrary_size(value) {
+                    styles.outline_width = Some(val);
+                    "outline-width"
+                } else {
+                    styles.outline_color = Some(parse_color(value).ok_or_else(invalid)?);
+                    "outline-color"
                 }
-                bg if bg.starts_with("bg-") => {
-                    if let Some(c) = parse_color(&name["bg-".len()..]) {
-                        background_color = Some(BackgroundColor(c));
-                    }
+            }
+            // Bevy only supports a single color for all four sides of a border.
+            border if border.starts_with("border-") => {
+                let value = &border["border-".len()..];
+                if let Some(val) = self.parse_arbitrary_size(value) {
+                    styles.border = FauxRect::all(val);
+                    "border-width"
+                } else {
+                    styles.border_color =
+                        Some(BorderColor(parse_color(value).ok_or_else(invalid)?));
+                    "border-color"
                 }
+            }
+            padding if padding.starts_with("p-") => {
+                styles.padding = FauxRect::all(
+                    self.parse_spacing(&padding["p-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "padding"
+            }
+            padding if padding.starts_with("pt-") => {
+                styles.padding.top = Some(
+                    self.parse_spacing(&padding["pt-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "padding-top"
+            }
+            padding if padding.starts_with("pr-") => {
+                styles.padding.right = Some(
+                    self.parse_spacing(&padding["pr-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "padding-right"
+            }
+            padding if padding.starts_with("pb-") => {
+                styles.padding.bottom = Some(
+                    self.parse_spacing(&padding["pb-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "padding-bottom"
+            }
+            padding if padding.starts_with("pl-") => {
+                styles.padding.left = Some(
+                    self.parse_spacing(&padding["pl-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "padding-left"
+            }
+            padding if padding.starts_with("px-") => {
+                let val = self
+                    .parse_spacing(&padding["px-".len()..])
+                    .ok_or_else(invalid)?;
+                styles.padding.left = Some(val);
+                styles.padding.right = Some(val);
+                "padding-x"
+            }
+            padding if padding.starts_with("py-") => {
+                let val = self
+                    .parse_spacing(&padding["py-".len()..])
+                    .ok_or_else(invalid)?;
+                styles.padding.top = Some(val);
+                styles.padding.bottom = Some(val);
+                "padding-y"
+            }
+            margin if margin.starts_with("m-") => {
+                styles.margin = FauxRect::all(
+                    self.parse_margin(&margin["m-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "margin"
+            }
+            margin if margin.starts_with("mt-") => {
+                styles.margin.top = Some(
+                    self.parse_margin(&margin["mt-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "margin-top"
+            }
+            margin if margin.starts_with("mr-") => {
+                styles.margin.right = Some(
+                    self.parse_margin(&margin["mr-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "margin-right"
+            }
+            margin if margin.starts_with("mb-") => {
+                styles.margin.bottom = Some(
+                    self.parse_margin(&margin["mb-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "margin-bottom"
+            }
+            margin if margin.starts_with("ml-") => {
+                styles.margin.left = Some(
+                    self.parse_margin(&margin["ml-".len()..])
+                        .ok_or_else(invalid)?,
+                );
+                "margin-left"
+            }
+            margin if margin.starts_with("mx-") => {
+                let val = self
+                    .parse_margin(&margin["mx-".len()..])
+                    .ok_or_else(invalid)?;
+                styles.margin.left = Some(val);
+                styles.margin.right = Some(val);
+                "margin-x"
+            }
+            margin if margin.starts_with("my-") => {
+                let val = self
+                    .parse_margin(&margin["my-".len()..])
+                    .ok_or_else(invalid)?;
+                styles.margin.top = Some(val);
+                styles.margin.bottom = Some(val);
+                "margin-y"
+            }
+            bg if bg.starts_with("bg-") => {
+                styles.background_color = Some(BackgroundColor(
+                    parse_color(&bg["bg-".len()..]).ok_or_else(invalid)?,
+                ));
+                "background-color"
+            }
 
-                "text-xs" => font_size = Some(self.rem * 0.75),
-                "text-sm" => font_size = Some(self.rem * 0.875),
-                "text-base" => font_size = Some(self.rem * 1.0),
-                "text-lg" => font_size = Some(self.rem * 1.125),
-                "text-xl" => font_size = Some(self.rem * 1.25),
-                "text-2xl" => font_size = Some(self.rem * 1.5),
-                "text-3xl" => font_size = Some(self.rem * 1.875),
-                "text-4xl" => font_size = Some(self.rem * 2.25),
-                "text-5xl" => font_size = Some(self.rem * 3.0),
-                "text-6xl" => font_size = Some(self.rem * 3.75),
-                "text-7xl" => font_size = Some(self.rem * 4.5),
-                "text-8xl" => font_size = Some(self.rem * 6.0),
-                "text-9xl" => font_size = Some(self.rem * 8.0),
-
-                text if text.starts_with("text-") => {
-                    if let Some(c) = parse_color(&name["text-".len()..]) {
-                        color = Some(c);
-                    }
-                }
+            "text-xs" | "text-sm" | "text-base" | "text-lg" | "text-xl" | "text-2xl"
+            | "text-3xl" | "text-4xl" | "text-5xl" | "text-6xl" | "text-7xl" | "text-8xl"
+            | "text-9xl" => {
+                let rems = match name {
+                    "text-xs" => 0.75,
+                    "text-sm" => 0.875,
+                    "text-base" => 1.0,
+                    "text-lg" => 1.125,
+                    "text-xl" => 1.25,
+                    "text-2xl" => 1.5,
+                    "text-3xl" => 1.875,
+                    "text-4xl" => 2.25,
+                    "text-5xl" => 3.0,
+                    "text-6xl" => 3.75,
+                    "text-7xl" => 4.5,
+                    "text-8xl" => 6.0,
+                    _ => 8.0,
+                };
+                styles.font_size = Some(self.rem * rems);
+                "font-size"
+            }
 
-                _ => {
-                    println!("Unknown name: {}", name)
+            "text-left" | "text-start" => {
+                styles.text_alignment = Some(TextAlignment::Left);
+                "text-align"
+            }
+            "text-center" => {
+                styles.text_alignment = Some(TextAlignment::Center);
+                "text-align"
+            }
+            "text-right" | "text-end" => {
+                styles.text_alignment = Some(TextAlignment::Right);
+                "text-align"
+            }
+            "whitespace-normal" | "break-normal" | "break-words" => {
+                styles.linebreak_behavior = Some(BreakLineOn::WordBoundary);
+                "line-break"
+            }
+            "whitespace-nowrap" => {
+                styles.linebreak_behavior = Some(BreakLineOn::NoWrap);
+                "line-break"
+            }
+            "break-all" => {
+                styles.linebreak_behavior = Some(BreakLineOn::AnyCharacter);
+                "line-break"
+            }
+            "truncate" => {
+                styles.overflow_x = Some(OverflowAxis::Clip);
+                styles.overflow_y = Some(OverflowAxis::Clip);
+                styles.linebreak_behavior = Some(BreakLineOn::NoWrap);
+                "truncate"
+            }
+
+            "font-thin" | "font-extralight" | "font-light" | "font-normal" | "font-medium"
+            | "font-semibold" | "font-bold" | "font-extrabold" | "font-black" => {
+                styles.font_weight = Some(match name {
+                    "font-thin" => FontWeight::Thin,
+                    "font-extralight" => FontWeight::ExtraLight,
+                    "font-light" => FontWeight::Light,
+                    "font-normal" => FontWeight::Normal,
+                    "font-medium" => FontWeight::Medium,
+                    "font-semibold" => FontWeight::SemiBold,
+                    "font-bold" => FontWeight::Bold,
+                    "font-extrabold" => FontWeight::ExtraBold,
+                    _ => FontWeight::Black,
+                });
+                "font-weight"
+            }
+            "italic" => {
+                styles.italic = Some(true);
+                "font-style"
+            }
+            "not-italic" => {
+                styles.italic = Some(false);
+                "font-style"
+            }
+            "font-sans" | "font-serif" | "font-mono" | "font-display" => {
+                styles.font_family = Some(name["font-".len()..].to_string());
+                "font-family"
+            }
+            family if family.starts_with("font-[") && family.ends_with(']') => {
+                let family = &family["font-[".len()..family.len() - 1];
+                styles.font_family = Some(family.replace('_', " "));
+                "font-family"
+            }
+
+            // Arbitrary values are either a font size, `text-[14px]`, or a color.
+            text if text.starts_with("text-") => {
+                let value = &text["text-".len()..];
+                if let Some(Val::Px(size)) = self.parse_arbitrary_size(value) {
+                    styles.font_size = Some(size);
+                    "font-size"
+                } else {
+                    styles.color = Some(parse_color(value).ok_or_else(invalid)?);
+                    "color"
                 }
             }
-        }
 
-        FauxStyle {
-            style,
-            background_color,
-            color,
-            font_size,
-        }
+            _ => return Err(Error::UnknownUtility(name.to_string())),
+        };
+
+        Ok(property)
     }
 }
 
+/// Reads the width out of a literal class like `border-2`, where the bare `border` means 1px.
+fn literal_width(name: &str, prefix: &str) -> f32 {
+    name.strip_prefix(prefix)
+        .and_then(parse_number)
+        .unwrap_or(1.0)
+}
+
+/// Parses a palette color, `red-500`, or an arbitrary one, `[#ef4444]`, optionally followed by
+/// an opacity modifier in percent, `red-500/50`, or as an arbitrary value, `red-500/[0.37]`.
 fn parse_color(name: &str) -> Option<Color> {
-    let (color_string, alpha_string) = name.split_once("/").unwrap_or((name, "255"));
-    let alpha = alpha_string.parse::<u8>().ok()?;
-
-    return match color_string {
-        "black" => Some(Color::rgba_u8(0, 0, 0, alpha)),
-        "white" => Some(Color::rgba_u8(255, 255, 255, alpha)),
-        "slate-50" => Some(Color::rgba_u8(248, 250, 252, alpha)),
-        "slate-100" => Some(Color::rgba_u8(241, 245, 249, alpha)),
-        "slate-200" => Some(Color::rgba_u8(226, 232, 240, alpha)),
-        "slate-300" => Some(Color::rgba_u8(203, 213, 225, alpha)),
-        "slate-400" => Some(Color::rgba_u8(148, 163, 184, alpha)),
-        "slate-500" => Some(Color::rgba_u8(100, 116, 139, alpha)),
-        "slate-600" => Some(Color::rgba_u8(71, 85, 105, alpha)),
-        "slate-700" => Some(Color::rgba_u8(51, 65, 85, alpha)),
-        "slate-800" => Some(Color::rgba_u8(30, 41, 59, alpha)),
-        "slate-900" => Some(Color::rgba_u8(15, 23, 42, alpha)),
-        "slate-950" => Some(Color::rgba_u8(2, 6, 23, alpha)),
-        "gray-50" => Some(Color::rgba_u8(249, 250, 251, alpha)),
-        "gray-100" => Some(Color::rgba_u8(243, 244, 246, alpha)),
-        "gray-200" => Some(Color::rgba_u8(229, 231, 235, alpha)),
-        "gray-300" => Some(Color::rgba_u8(209, 213, 219, alpha)),
-        "gray-400" => Some(Color::rgba_u8(156, 163, 175, alpha)),
-        "gray-500" => Some(Color::rgba_u8(107, 114, 128, alpha)),
-        "gray-600" => Some(Color::rgba_u8(75, 85, 99, alpha)),
-        "gray-700" => Some(Color::rgba_u8(55, 65, 81, alpha)),
-        "gray-800" => Some(Color::rgba_u8(31, 41, 55, alpha)),
-        "gray-900" => Some(Color::rgba_u8(17, 24, 39, alpha)),
-        "gray-950" => Some(Color::rgba_u8(3, 7, 18, alpha)),
-        "zinc-50" => Some(Color::rgba_u8(250, 250, 250, alpha)),
-        "zinc-100" => Some(Color::rgba_u8(244, 244, 245, alpha)),
-        "zinc-200" => Some(Color::rgba_u8(228, 228, 231, alpha)),
-        "zinc-300" => Some(Color::rgba_u8(212, 212, 216, alpha)),
-        "zinc-400" => Some(Color::rgba_u8(161, 161, 170, alpha)),
-        "zinc-500" => Some(Color::rgba_u8(113, 113, 122, alpha)),
-        "zinc-600" => Some(Color::rgba_u8(82, 82, 91, alpha)),
-        "zinc-700" => Some(Color::rgba_u8(63, 63, 70, alpha)),
-        "zinc-800" => Some(Color::rgba_u8(39, 39, 42, alpha)),
-        "zinc-900" => Some(Color::rgba_u8(24, 24, 27, alpha)),
-        "zinc-950" => Some(Color::rgba_u8(9, 9, 11, alpha)),
-        "neutral-50" => Some(Color::rgba_u8(250, 250, 250, alpha)),
-        "neutral-100" => Some(Color::rgba_u8(245, 245, 245, alpha)),
-        "neutral-200" => Some(Color::rgba_u8(229, 229, 229, alpha)),
-        "neutral-300" => Some(Color::rgba_u8(212, 212, 212, alpha)),
-        "neutral-400" => Some(Color::rgba_u8(163, 163, 163, alpha)),
-        "neutral-500" => Some(Color::rgba_u8(115, 115, 115, alpha)),
-        "neutral-600" => Some(Color::rgba_u8(82, 82, 82, alpha)),
-        "neutral-700" => Some(Color::rgba_u8(64, 64, 64, alpha)),
-        "neutral-800" => Some(Color::rgba_u8(38, 38, 38, alpha)),
-        "neutral-900" => Some(Color::rgba_u8(23, 23, 23, alpha)),
-        "neutral-950" => Some(Color::rgba_u8(10, 10, 10, alpha)),
-        "stone-50" => Some(Color::rgba_u8(250, 250, 249, alpha)),
-        "stone-100" => Some(Color::rgba_u8(245, 245, 244, alpha)),
-        "stone-200" => Some(Color::rgba_u8(231, 229, 228, alpha)),
-        "stone-300" => Some(Color::rgba_u8(214, 211, 209, alpha)),
-        "stone-400" => Some(Color::rgba_u8(168, 162, 158, alpha)),
-        "stone-500" => Some(Color::rgba_u8(120, 113, 108, alpha)),
-        "stone-600" => Some(Color::rgba_u8(87, 83, 78, alpha)),
-        "stone-700" => Some(Color::rgba_u8(68, 64, 60, alpha)),
-        "stone-800" => Some(Color::rgba_u8(41, 37, 36, alpha)),
-        "stone-900" => Some(Color::rgba_u8(28, 25, 23, alpha)),
-        "stone-950" => Some(Color::rgba_u8(12, 10, 9, alpha)),
-        "red-50" => Some(Color::rgba_u8(254, 242, 242, alpha)),
-        "red-100" => Some(Color::rgba_u8(254, 226, 226, alpha)),
-        "red-200" => Some(Color::rgba_u8(254, 202, 202, alpha)),
-        "red-300" => Some(Color::rgba_u8(252, 165, 165, alpha)),
-        "red-400" => Some(Color::rgba_u8(248, 113, 113, alpha)),
-        "red-500" => Some(Color::rgba_u8(239, 68, 68, alpha)),
-        "red-600" => Some(Color::rgba_u8(220, 38, 38, alpha)),
-        "red-700" => Some(Color::rgba_u8(185, 28, 28, alpha)),
-        "red-800" => Some(Color::rgba_u8(153, 27, 27, alpha)),
-        "red-900" => Some(Color::rgba_u8(127, 29, 29, alpha)),
-        "red-950" => Some(Color::rgba_u8(69, 10, 10, alpha)),
-        "orange-50" => Some(Color::rgba_u8(255, 247, 237, alpha)),
-        "orange-100" => Some(Color::rgba_u8(255, 237, 213, alpha)),
-        "orange-200" => Some(Color::rgba_u8(254, 215, 170, alpha)),
-        "orange-300" => Some(Color::rgba_u8(253, 186, 116, alpha)),
-        "orange-400" => Some(Color::rgba_u8(251, 146, 60, alpha)),
-        "orange-500" => Some(Color::rgba_u8(249, 115, 22, alpha)),
-        "orange-600" => Some(Color::rgba_u8(234, 88, 12, alpha)),
-        "orange-700" => Some(Color::rgba_u8(194, 65, 12, alpha)),
-        "orange-800" => Some(Color::rgba_u8(154, 52, 18, alpha)),
-        "orange-900" => Some(Color::rgba_u8(124, 45, 18, alpha)),
-        "orange-950" => Some(Color::rgba_u8(67, 20, 7, alpha)),
-        "amber-50" => Some(Color::rgba_u8(255, 251, 235, alpha)),
-        "amber-100" => Some(Color::rgba_u8(254, 243, 199, alpha)),
-        "amber-200" => Some(Color::rgba_u8(253, 230, 138, alpha)),
-        "amber-300" => Some(Color::rgba_u8(252, 211, 77, alpha)),
-        "amber-400" => Some(Color::rgba_u8(251, 191, 36, alpha)),
-        "amber-500" => Some(Color::rgba_u8(245, 158, 11, alpha)),
-        "amber-600" => Some(Color::rgba_u8(217, 119, 6, alpha)),
-        "amber-700" => Some(Color::rgba_u8(180, 83, 9, alpha)),
-        "amber-800" => Some(Color::rgba_u8(146, 64, 14, alpha)),
-        "amber-900" => Some(Color::rgba_u8(120, 53, 15, alpha)),
-        "amber-950" => Some(Color::rgba_u8(69, 26, 3, alpha)),
-        "yellow-50" => Some(Color::rgba_u8(254, 252, 232, alpha)),
-        "yellow-100" => Some(Color::rgba_u8(254, 249, 195, alpha)),
-        "yellow-200" => Some(Color::rgba_u8(254, 240, 138, alpha)),
-        "yellow-300" => Some(Color::rgba_u8(253, 224, 71, alpha)),
-        "yellow-400" => Some(Color::rgba_u8(250, 204, 21, alpha)),
-        "yellow-500" => Some(Color::rgba_u8(234, 179, 8, alpha)),
-        "yellow-600" => Some(Color::rgba_u8(202, 138, 4, alpha)),
-        "yellow-700" => Some(Color::rgba_u8(161, 98, 7, alpha)),
-        "yellow-800" => Some(Color::rgba_u8(133, 77, 14, alpha)),
-        "yellow-900" => Some(Color::rgba_u8(113, 63, 18, alpha)),
-        "yellow-950" => Some(Color::rgba_u8(66, 32, 6, alpha)),
-        "lime-50" => Some(Color::rgba_u8(247, 254, 231, alpha)),
-        "lime-100" => Some(Color::rgba_u8(236, 252, 203, alpha)),
-        "lime-200" => Some(Color::rgba_u8(217, 249, 157, alpha)),
-        "lime-300" => Some(Color::rgba_u8(190, 242, 100, alpha)),
-        "lime-400" => Some(Color::rgba_u8(163, 230, 53, alpha)),
-        "lime-500" => Some(Color::rgba_u8(132, 204, 22, alpha)),
-        "lime-600" => Some(Color::rgba_u8(101, 163, 13, alpha)),
-        "lime-700" => Some(Color::rgba_u8(77, 124, 15, alpha)),
-        "lime-800" => Some(Color::rgba_u8(63, 98, 18, alpha)),
-        "lime-900" => Some(Color::rgba_u8(54, 83, 20, alpha)),
-        "lime-950" => Some(Color::rgba_u8(26, 46, 5, alpha)),
-        "green-50" => Some(Color::rgba_u8(240, 253, 244, alpha)),
-        "green-100" => Some(Color::rgba_u8(220, 252, 231, alpha)),
-        "green-200" => Some(Color::rgba_u8(187, 247, 208, alpha)),
-        "green-300" => Some(Color::rgba_u8(134, 239, 172, alpha)),
-        "green-400" => Some(Color::rgba_u8(74, 222, 128, alpha)),
-        "green-500" => Some(Color::rgba_u8(34, 197, 94, alpha)),
-        "green-600" => Some(Color::rgba_u8(22, 163, 74, alpha)),
-        "green-700" => Some(Color::rgba_u8(21, 128, 61, alpha)),
-        "green-800" => Some(Color::rgba_u8(22, 101, 52, alpha)),
-        "green-900" => Some(Color::rgba_u8(20, 83, 45, alpha)),
-        "green-950" => Some(Color::rgba_u8(5, 46, 22, alpha)),
-        "emerald-50" => Some(Color::rgba_u8(236, 253, 245, alpha)),
-        "emerald-100" => Some(Color::rgba_u8(209, 250, 229, alpha)),
-        "emerald-200" => Some(Color::rgba_u8(167, 243, 208, alpha)),
-        "emerald-300" => Some(Color::rgba_u8(110, 231, 183, alpha)),
-        "emerald-400" => Some(Color::rgba_u8(52, 211, 153, alpha)),
-        "emerald-500" => Some(Color::rgba_u8(16, 185, 129, alpha)),
-        "emerald-600" => Some(Color::rgba_u8(5, 150, 105, alpha)),
-        "emerald-700" => Some(Color::rgba_u8(4, 120, 87, alpha)),
-        "emerald-800" => Some(Color::rgba_u8(6, 95, 70, alpha)),
-        "emerald-900" => Some(Color::rgba_u8(6, 78, 59, alpha)),
-        "emerald-950" => Some(Color::rgba_u8(2, 44, 34, alpha)),
-        "teal-50" => Some(Color::rgba_u8(240, 253, 250, alpha)),
-        "teal-100" => Some(Color::rgba_u8(204, 251, 241, alpha)),
-        "teal-200" => Some(Color::rgba_u8(153, 246, 228, alpha)),
-        "teal-300" => Some(Color::rgba_u8(94, 234, 212, alpha)),
-        "teal-400" => Some(Color::rgba_u8(45, 212, 191, alpha)),
-        "teal-500" => Some(Color::rgba_u8(20, 184, 166, alpha)),
-        "teal-600" => Some(Color::rgba_u8(13, 148, 136, alpha)),
-        "teal-700" => Some(Color::rgba_u8(15, 118, 110, alpha)),
-        "teal-800" => Some(Color::rgba_u8(17, 94, 89, alpha)),
-        "teal-900" => Some(Color::rgba_u8(19, 78, 74, alpha)),
-        "teal-950" => Some(Color::rgba_u8(4, 47, 46, alpha)),
-        "cyan-50" => Some(Color::rgba_u8(236, 254, 255, alpha)),
-        "cyan-100" => Some(Color::rgba_u8(207, 250, 254, alpha)),
-        "cyan-200" => Some(Color::rgba_u8(165, 243, 252, alpha)),
-        "cyan-300" => Some(Color::rgba_u8(103, 232, 249, alpha)),
-        "cyan-400" => Some(Color::rgba_u8(34, 211, 238, alpha)),
-        "cyan-500" => Some(Color::rgba_u8(6, 182, 212, alpha)),
-        "cyan-600" => Some(Color::rgba_u8(8, 145, 178, alpha)),
-        "cyan-700" => Some(Color::rgba_u8(14, 116, 144, alpha)),
-        "cyan-800" => Some(Color::rgba_u8(21, 94, 117, alpha)),
-        "cyan-900" => Some(Color::rgba_u8(22, 78, 99, alpha)),
-        "cyan-950" => Some(Color::rgba_u8(8, 51, 68, alpha)),
-        "sky-50" => Some(Color::rgba_u8(240, 249, 255, alpha)),
-        "sky-100" => Some(Color::rgba_u8(224, 242, 254, alpha)),
-        "sky-200" => Some(Color::rgba_u8(186, 230, 253, alpha)),
-        "sky-300" => Some(Color::rgba_u8(125, 211, 252, alpha)),
-        "sky-400" => Some(Color::rgba_u8(56, 189, 248, alpha)),
-        "sky-500" => Some(Color::rgba_u8(14, 165, 233, alpha)),
-        "sky-600" => Some(Color::rgba_u8(2, 132, 199, alpha)),
-        "sky-700" => Some(Color::rgba_u8(3, 105, 161, alpha)),
-        "sky-800" => Some(Color::rgba_u8(7, 89, 133, alpha)),
-        "sky-900" => Some(Color::rgba_u8(12, 74, 110, alpha)),
-        "sky-950" => Some(Color::rgba_u8(8, 47, 73, alpha)),
-        "blue-50" => Some(Color::rgba_u8(239, 246, 255, alpha)),
-        "blue-100" => Some(Color::rgba_u8(219, 234, 254, alpha)),
-        "blue-200" => Some(Color::rgba_u8(191, 219, 254, alpha)),
-        "blue-300" => Some(Color::rgba_u8(147, 197, 253, alpha)),
-        "blue-400" => Some(Color::rgba_u8(96, 165, 250, alpha)),
-        "blue-500" => Some(Color::rgba_u8(59, 130, 246, alpha)),
-        "blue-600" => Some(Color::rgba_u8(37, 99, 235, alpha)),
-        "blue-700" => Some(Color::rgba_u8(29, 78, 216, alpha)),
-        "blue-800" => Some(Color::rgba_u8(30, 64, 175, alpha)),
-        "blue-900" => Some(Color::rgba_u8(30, 58, 138, alpha)),
-        "blue-950" => Some(Color::rgba_u8(23, 37, 84, alpha)),
-        "indigo-50" => Some(Color::rgba_u8(238, 242, 255, alpha)),
-        "indigo-100" => Some(Color::rgba_u8(224, 231, 255, alpha)),
-        "indigo-200" => Some(Color::rgba_u8(199, 210, 254, alpha)),
-        "indigo-300" => Some(Color::rgba_u8(165, 180, 252, alpha)),
-        "indigo-400" => Some(Color::rgba_u8(129, 140, 248, alpha)),
-        "indigo-500" => Some(Color::rgba_u8(99, 102, 241, alpha)),
-        "indigo-600" => Some(Color::rgba_u8(79, 70, 229, alpha)),
-        "indigo-700" => Some(Color::rgba_u8(67, 56, 202, alpha)),
-        "indigo-800" => Some(Color::rgba_u8(55, 48, 163, alpha)),
-        "indigo-900" => Some(Color::rgba_u8(49, 46, 129, alpha)),
-        "indigo-950" => Some(Color::rgba_u8(30, 27, 75, alpha)),
-        "violet-50" => Some(Color::rgba_u8(245, 243, 255, alpha)),
-        "violet-100" => Some(Color::rgba_u8(237, 233, 254, alpha)),
-        "violet-200" => Some(Color::rgba_u8(221, 214, 254, alpha)),
-        "violet-300" => Some(Color::rgba_u8(196, 181, 253, alpha)),
-        "violet-400" => Some(Color::rgba_u8(167, 139, 250, alpha)),
-        "violet-500" => Some(Color::rgba_u8(139, 92, 246, alpha)),
-        "violet-600" => Some(Color::rgba_u8(124, 58, 237, alpha)),
-        "violet-700" => Some(Color::rgba_u8(109, 40, 217, alpha)),
-        "violet-800" => Some(Color::rgba_u8(91, 33, 182, alpha)),
-        "violet-900" => Some(Color::rgba_u8(76, 29, 149, alpha)),
-        "violet-950" => Some(Color::rgba_u8(46, 16, 101, alpha)),
-        "purple-50" => Some(Color::rgba_u8(250, 245, 255, alpha)),
-        "purple-100" => Some(Color::rgba_u8(243, 232, 255, alpha)),
-        "purple-200" => Some(Color::rgba_u8(233, 213, 255, alpha)),
-        "purple-300" => Some(Color::rgba_u8(216, 180, 254, alpha)),
-        "purple-400" => Some(Color::rgba_u8(192, 132, 252, alpha)),
-        "purple-500" => Some(Color::rgba_u8(168, 85, 247, alpha)),
-        "purple-600" => Some(Color::rgba_u8(147, 51, 234, alpha)),
-        "purple-700" => Some(Color::rgba_u8(126, 34, 206, alpha)),
-        "purple-800" => Some(Color::rgba_u8(107, 33, 168, alpha)),
-        "purple-900" => Some(Color::rgba_u8(88, 28, 135, alpha)),
-        "purple-950" => Some(Color::rgba_u8(59, 7, 100, alpha)),
-        "fuchsia-50" => Some(Color::rgba_u8(253, 244, 255, alpha)),
-        "fuchsia-100" => Some(Color::rgba_u8(250, 232, 255, alpha)),
-        "fuchsia-200" => Some(Color::rgba_u8(245, 208, 254, alpha)),
-        "fuchsia-300" => Some(Color::rgba_u8(240, 171, 252, alpha)),
-        "fuchsia-400" => Some(Color::rgba_u8(232, 121, 249, alpha)),
-        "fuchsia-500" => Some(Color::rgba_u8(217, 70, 239, alpha)),
-        "fuchsia-600" => Some(Color::rgba_u8(192, 38, 211, alpha)),
-        "fuchsia-700" => Some(Color::rgba_u8(162, 28, 175, alpha)),
-        "fuchsia-800" => Some(Color::rgba_u8(134, 25, 143, alpha)),
-        "fuchsia-900" => Some(Color::rgba_u8(112, 26, 117, alpha)),
-        "fuchsia-950" => Some(Color::rgba_u8(74, 4, 78, alpha)),
-        "pink-50" => Some(Color::rgba_u8(253, 242, 248, alpha)),
-        "pink-100" => Some(Color::rgba_u8(252, 231, 243, alpha)),
-        "pink-200" => Some(Color::rgba_u8(251, 207, 232, alpha)),
-        "pink-300" => Some(Color::rgba_u8(249, 168, 212, alpha)),
-        "pink-400" => Some(Color::rgba_u8(244, 114, 182, alpha)),
-        "pink-500" => Some(Color::rgba_u8(236, 72, 153, alpha)),
-        "pink-600" => Some(Color::rgba_u8(219, 39, 119, alpha)),
-        "pink-700" => Some(Color::rgba_u8(190, 24, 93, alpha)),
-        "pink-800" => Some(Color::rgba_u8(157, 23, 77, alpha)),
-        "pink-900" => Some(Color::rgba_u8(131, 24, 67, alpha)),
-        "pink-950" => Some(Color::rgba_u8(80, 7, 36, alpha)),
-        "rose-50" => Some(Color::rgba_u8(255, 241, 242, alpha)),
-        "rose-100" => Some(Color::rgba_u8(255, 228, 230, alpha)),
-        "rose-200" => Some(Color::rgba_u8(254, 205, 211, alpha)),
-        "rose-300" => Some(Color::rgba_u8(253, 164, 175, alpha)),
-        "rose-400" => Some(Color::rgba_u8(251, 113, 133, alpha)),
-        "rose-500" => Some(Color::rgba_u8(244, 63, 94, alpha)),
-        "rose-600" => Some(Color::rgba_u8(225, 29, 72, alpha)),
-        "rose-700" => Some(Color::rgba_u8(190, 18, 60, alpha)),
-        "rose-800" => Some(Color::rgba_u8(159, 18, 57, alpha)),
-        "rose-900" => Some(Color::rgba_u8(136, 19, 55, alpha)),
-        "rose-950" => Some(Color::rgba_u8(76, 5, 25, alpha)),
-        _ => None,
+    let (color, alpha_string) = match name.strip_prefix("[") {
+        Some(arbitrary) => {
+            let (color_string, modifier) = arbitrary.split_once("]")?;
+            let alpha_string = match modifier {
+                "" => None,
+                modifier => Some(modifier.strip_prefix("/")?),
+            };
+            (parse_css_color(color_string)?, alpha_string)
+        }
+        None => match name.split_once("/") {
+            Some((color_string, alpha_string)) => {
+                (palette_color(color_string)?, Some(alpha_string))
+            }
+            None => (palette_color(name)?, None),
+        },
     };
+
+    match alpha_string {
+        Some(alpha_string) => Some(color.with_a(parse_opacity_modifier(alpha_string)?)),
+        None => Some(color),
+    }
+}
+
+fn parse_opacity_modifier(s: &str) -> Option<f32> {
+    if let Some(arbitrary) = s.strip_prefix("[").and_then(|s| s.strip_suffix("]")) {
+        return parse_unit_interval(arbitrary);
+    }
+
+    let percent = s.parse::<u8>().ok().filter(|percent| *percent <= 100)?;
+    Some(percent as f32 / 100.0)
 }
 
-fn parse_size(size: &str) -> Option<Val> {
-    if size.ends_with("%") {
-        return size[0..size.len() - 1]
-            .parse::<f32>()
-            .map(Val::Percent)
-            .ok();
+fn palette_color(name: &str) -> Option<Color> {
+    match name {
+        "black" => Some(Color::rgb_u8(0, 0, 0)),
+        "white" => Some(Color::rgb_u8(255, 255, 255)),
+        "slate-50" => Some(Color::rgb_u8(248, 250, 252)),
+        "slate-100" => Some(Color::rgb_u8(241, 245, 249)),
+        "slate-200" => Some(Color::rgb_u8(226, 232, 240)),
+        "slate-300" => Some(Color::rgb_u8(203, 213, 225)),
+        "slate-400" => Some(Color::rgb_u8(148, 163, 184)),
+        "slate-500" => Some(Color::rgb_u8(100, 116, 139)),
+        "slate-600" => Some(Color::rgb_u8(71, 85, 105)),
+        "slate-700" => Some(Color::rgb_u8(51, 65, 85)),
+        "slate-800" => Some(Color::rgb_u8(30, 41, 59)),
+        "slate-900" => Some(Color::rgb_u8(15, 23, 42)),
+        "slate-950" => Some(Color::rgb_u8(2, 6, 23)),
+        "gray-50" => Some(Color::rgb_u8(249, 250, 251)),
+        "gray-100" => Some(Color::rgb_u8(243, 244, 246)),
+        "gray-200" => Some(Color::rgb_u8(229, 231, 235)),
+        "gray-300" => Some(Color::rgb_u8(209, 213, 219)),
+        "gray-400" => Some(Color::rgb_u8(156, 163, 175)),
+        "gray-500" => Some(Color::rgb_u8(107, 114, 128)),
+        "gray-600" => Some(Color::rgb_u8(75, 85, 99)),
+        "gray-700" => Some(Color::rgb_u8(55, 65, 81)),
+        "gray-800" => Some(Color::rgb_u8(31, 41, 55)),
+        "gray-900" => Some(Color::rgb_u8(17, 24, 39)),
+        "gray-950" => Some(Color::rgb_u8(3, 7, 18)),
+        "zinc-50" => Some(Color::rgb_u8(250, 250, 250)),
+        "zinc-100" => Some(Color::rgb_u8(244, 244, 245)),
+        "zinc-200" => Some(Color::rgb_u8(228, 228, 231)),
+        "zinc-300" => Some(Color::rgb_u8(212, 212, 216)),
+        "zinc-400" => Some(Color::rgb_u8(161, 161, 170)),
+        "zinc-500" => Some(Color::rgb_u8(113, 113, 122)),
+        "zinc-600" => Some(Color::rgb_u8(82, 82, 91)),
+        "zinc-700" => Some(Color::rgb_u8(63, 63, 70)),
+        "zinc-800" => Some(Color::rgb_u8(39, 39, 42)),
+        "zinc-900" => Some(Color::rgb_u8(24, 24, 27)),
+        "zinc-950" => Some(Color::rgb_u8(9, 9, 11)),
+        "neutral-50" => Some(Color::rgb_u8(250, 250, 250)),
+        "neutral-100" => Some(Color::rgb_u8(245, 245, 245)),
+        "neutral-200" => Some(Color::rgb_u8(229, 229, 229)),
+        "neutral-300" => Some(Color::rgb_u8(212, 212, 212)),
+        "neutral-400" => Some(Color::rgb_u8(163, 163, 163)),
+        "neutral-500" => Some(Color::rgb_u8(115, 115, 115)),
+        "neutral-600" => Some(Color::rgb_u8(82, 82, 82)),
+        "neutral-700" => Some(Color::rgb_u8(64, 64, 64)),
+        "neutral-800" => Some(Color::rgb_u8(38, 38, 38)),
+        "neutral-900" => Some(Color::rgb_u8(23, 23, 23)),
+        "neutral-950" => Some(Color::rgb_u8(10, 10, 10)),
+        "stone-50" => Some(Color::rgb_u8(250, 250, 249)),
+        "stone-100" => Some(Color::rgb_u8(245, 245, 244)),
+        "stone-200" => Some(Color::rgb_u8(231, 229, 228)),
+        "stone-300" => Some(Color::rgb_u8(214, 211, 209)),
+        "stone-400" => Some(Color::rgb_u8(168, 162, 158)),
+        "stone-500" => Some(Color::rgb_u8(120, 113, 108)),
+        "stone-600" => Some(Color::rgb_u8(87, 83, 78)),
+        "stone-700" => Some(Color::rgb_u8(68, 64, 60)),
+        "stone-800" => Some(Color::rgb_u8(41, 37, 36)),
+        "stone-900" => Some(Color::rgb_u8(28, 25, 23)),
+        "stone-950" => Some(Color::rgb_u8(12, 10, 9)),
+        "red-50" => Some(Color::rgb_u8(254, 242, 242)),
+        "red-100" => Some(Color::rgb_u8(254, 226, 226)),
+        "red-200" => Some(Color::rgb_u8(254, 202, 202)),
+        "red-300" => Some(Color::rgb_u8(252, 165, 165)),
+        "red-400" => Some(Color::rgb_u8(248, 113, 113)),
+        "red-500" => Some(Color::rgb_u8(239, 68, 68)),
+        "red-600" => Some(Color::rgb_u8(220, 38, 38)),
+        "red-700" => Some(Color::rgb_u8(185, 28, 28)),
+        "red-800" => Some(Color::rgb_u8(153, 27, 27)),
+        "red-900" => Some(Color::rgb_u8(127, 29, 29)),
+        "red-950" => Some(Color::rgb_u8(69, 10, 10)),
+        "orange-50" => Some(Color::rgb_u8(255, 247, 237)),
+        "orange-100" => Some(Color::rgb_u8(255, 237, 213)),
+        "orange-200" => Some(Color::rgb_u8(254, 215, 170)),
+        "orange-300" => Some(Color::rgb_u8(253, 186, 116)),
+        "orange-400" => Some(Color::rgb_u8(251, 146, 60)),
+        "orange-500" => Some(Color::rgb_u8(249, 115, 22)),
+        "orange-600" => Some(Color::rgb_u8(234, 88, 12)),
+        "orange-700" => Some(Color::rgb_u8(194, 65, 12)),
+        "orange-800" => Some(Color::rgb_u8(154, 52, 18)),
+        "orange-900" => Some(Color::rgb_u8(124, 45, 18)),
+        "orange-950" => Some(Color::rgb_u8(67, 20, 7)),
+        "amber-50" => Some(Color::rgb_u8(255, 251, 235)),
+        "amber-100" => Some(Color::rgb_u8(254, 243, 199)),
+        "amber-200" => Some(Color::rgb_u8(253, 230, 138)),
+        "amber-300" => Some(Color::rgb_u8(252, 211, 77)),
+        "amber-400" => Some(Color::rgb_u8(251, 191, 36)),
+        "amber-500" => Some(Color::rgb_u8(245, 158, 11)),
+        "amber-600" => Some(Color::rgb_u8(217, 119, 6)),
+        "amber-700" => Some(Color::rgb_u8(180, 83, 9)),
+        "amber-800" => Some(Color::rgb_u8(146, 64, 14)),
+        "amber-900" => Some(Color::rgb_u8(120, 53, 15)),
+        "amber-950" => Some(Color::rgb_u8(69, 26, 3)),
+        "yellow-50" => Some(Color::rgb_u8(254, 252, 232)),
+        "yellow-100" => Some(Color::rgb_u8(254, 249, 195)),
+        "yellow-200" => Some(Color::rgb_u8(254, 240, 138)),
+        "yellow-300" => Some(Color::rgb_u8(253, 224, 71)),
+        "yellow-400" => Some(Color::rgb_u8(250, 204, 21)),
+        "yellow-500" => Some(Color::rgb_u8(234, 179, 8)),
+        "yellow-600" => Some(Color::rgb_u8(202, 138, 4)),
+        "yellow-700" => Some(Color::rgb_u8(161, 98, 7)),
+        "yellow-800" => Some(Color::rgb_u8(133, 77, 14)),
+        "yellow-900" => Some(Color::rgb_u8(113, 63, 18)),
+        "yellow-950" => Some(Color::rgb_u8(66, 32, 6)),
+        "lime-50" => Some(Color::rgb_u8(247, 254, 231)),
+        "lime-100" => Some(Color::rgb_u8(236, 252, 203)),
+        "lime-200" => Some(Color::rgb_u8(217, 249, 157)),
+        "lime-300" => Some(Color::rgb_u8(190, 242, 100)),
+        "lime-400" => Some(Color::rgb_u8(163, 230, 53)),
+        "lime-500" => Some(Color::rgb_u8(132, 204, 22)),
+        "lime-600" => Some(Color::rgb_u8(101, 163, 13)),
+        "lime-700" => Some(Color::rgb_u8(77, 124, 15)),
+        "lime-800" => Some(Color::rgb_u8(63, 98, 18)),
+        "lime-900" => Some(Color::rgb_u8(54, 83, 20)),
+        "lime-950" => Some(Color::rgb_u8(26, 46, 5)),
+        "green-50" => Some(Color::rgb_u8(240, 253, 244)),
+        "green-100" => Some(Color::rgb_u8(220, 252, 231)),
+        "green-200" => Some(Color::rgb_u8(187, 247, 208)),
+        "green-300" => Some(Color::rgb_u8(134, 239, 172)),
+        "green-400" => Some(Color::rgb_u8(74, 222, 128)),
+        "green-500" => Some(Color::rgb_u8(34, 197, 94)),
+        "green-600" => Some(Color::rgb_u8(22, 163, 74)),
+        "green-700" => Some(Color::rgb_u8(21, 128, 61)),
+        "green-800" => Some(Color::rgb_u8(22, 101, 52)),
+        "green-900" => Some(Color::rgb_u8(20, 83, 45)),
+        "green-950" => Some(Color::rgb_u8(5, 46, 22)),
+        "emerald-50" => Some(Color::rgb_u8(236, 253, 245)),
+        "emerald-100" => Some(Color::rgb_u8(209, 250, 229)),
+        "emerald-200" => Some(Color::rgb_u8(167, 243, 208)),
+        "emerald-300" => Some(Color::rgb_u8(110, 231, 183)),
+        "emerald-400" => Some(Color::rgb_u8(52, 211, 153)),
+        "emerald-500" => Some(Color::rgb_u8(16, 185, 129)),
+        "emerald-600" => Some(Color::rgb_u8(5, 150, 105)),
+        "emerald-700" => Some(Color::rgb_u8(4, 120, 87)),
+        "emerald-800" => Some(Color::rgb_u8(6, 95, 70)),
+        "emerald-900" => Some(Color::rgb_u8(6, 78, 59)),
+        "emerald-950" => Some(Color::rgb_u8(2, 44, 34)),
+        "teal-50" => Some(Color::rgb_u8(240, 253, 250)),
+        "teal-100" => Some(Color::rgb_u8(204, 251, 241)),
+        "teal-200" => Some(Color::rgb_u8(153, 246, 228)),
+        "teal-300" => Some(Color::rgb_u8(94, 234, 212)),
+        "teal-400" => Some(Color::rgb_u8(45, 212, 191)),
+        "teal-500" => Some(Color::rgb_u8(20, 184, 166)),
+        "teal-600" => Some(Color::rgb_u8(13, 148, 136)),
+        "teal-700" => Some(Color::rgb_u8(15, 118, 110)),
+        "teal-800" => Some(Color::rgb_u8(17, 94, 89)),
+        "teal-900" => Some(Color::rgb_u8(19, 78, 74)),
+        "teal-950" => Some(Color::rgb_u8(4, 47, 46)),
+        "cyan-50" => Some(Color::rgb_u8(236, 254, 255)),
+        "cyan-100" => Some(Color::rgb_u8(207, 250, 254)),
+        "cyan-200" => Some(Color::rgb_u8(165, 243, 252)),
+        "cyan-300" => Some(Color::rgb_u8(103, 232, 249)),
+        "cyan-400" => Some(Color::rgb_u8(34, 211, 238)),
+        "cyan-500" => Some(Color::rgb_u8(6, 182, 212)),
+        "cyan-600" => Some(Color::rgb_u8(8, 145, 178)),
+        "cyan-700" => Some(Color::rgb_u8(14, 116, 144)),
+        "cyan-800" => Some(Color::rgb_u8(21, 94, 117)),
+        "cyan-900" => Some(Color::rgb_u8(22, 78, 99)),
+        "cyan-950" => Some(Color::rgb_u8(8, 51, 68)),
+        "sky-50" => Some(Color::rgb_u8(240, 249, 255)),
+        "sky-100" => Some(Color::rgb_u8(224, 242, 254)),
+        "sky-200" => Some(Color::rgb_u8(186, 230, 253)),
+        "sky-300" => Some(Color::rgb_u8(125, 211, 252)),
+        "sky-400" => Some(Color::rgb_u8(56, 189, 248)),
+        "sky-500" => Some(Color::rgb_u8(14, 165, 233)),
+        "sky-600" => Some(Color::rgb_u8(2, 132, 199)),
+        "sky-700" => Some(Color::rgb_u8(3, 105, 161)),
+        "sky-800" => Some(Color::rgb_u8(7, 89, 133)),
+        "sky-900" => Some(Color::rgb_u8(12, 74, 110)),
+        "sky-950" => Some(Color::rgb_u8(8, 47, 73)),
+        "blue-50" => Some(Color::rgb_u8(239, 246, 255)),
+        "blue-100" => Some(Color::rgb_u8(219, 234, 254)),
+        "blue-200" => Some(Color::rgb_u8(191, 219, 254)),
+        "blue-300" => Some(Color::rgb_u8(147, 197, 253)),
+        "blue-400" => Some(Color::rgb_u8(96, 165, 250)),
+        "blue-500" => Some(Color::rgb_u8(59, 130, 246)),
+        "blue-600" => Some(Color::rgb_u8(37, 99, 235)),
+        "blue-700" => Some(Color::rgb_u8(29, 78, 216)),
+        "blue-800" => Some(Color::rgb_u8(30, 64, 175)),
+        "blue-900" => Some(Color::rgb_u8(30, 58, 138)),
+        "blue-950" => Some(Color::rgb_u8(23, 37, 84)),
+        "indigo-50" => Some(Color::rgb_u8(238, 242, 255)),
+        "indigo-100" => Some(Color::rgb_u8(224, 231, 255)),
+        "indigo-200" => Some(Color::rgb_u8(199, 210, 254)),
+        "indigo-300" => Some(Color::rgb_u8(165, 180, 252)),
+        "indigo-400" => Some(Color::rgb_u8(129, 140, 248)),
+        "indigo-500" => Some(Color::rgb_u8(99, 102, 241)),
+        "indigo-600" => Some(Color::rgb_u8(79, 70, 229)),
+        "indigo-700" => Some(Color::rgb_u8(67, 56, 202)),
+        "indigo-800" => Some(Color::rgb_u8(55, 48, 163)),
+        "indigo-900" => Some(Color::rgb_u8(49, 46, 129)),
+        "indigo-950" => Some(Color::rgb_u8(30, 27, 75)),
+        "violet-50" => Some(Color::rgb_u8(245, 243, 255)),
+        "violet-100" => Some(Color::rgb_u8(237, 233, 254)),
+        "violet-200" => Some(Color::rgb_u8(221, 214, 254)),
+        "violet-300" => Some(Color::rgb_u8(196, 181, 253)),
+        "violet-400" => Some(Color::rgb_u8(167, 139, 250)),
+        "violet-500" => Some(Color::rgb_u8(139, 92, 246)),
+        "violet-600" => Some(Color::rgb_u8(124, 58, 237)),
+        "violet-700" => Some(Color::rgb_u8(109, 40, 217)),
+        "violet-800" => Some(Color::rgb_u8(91, 33, 182)),
+        "violet-900" => Some(Color::rgb_u8(76, 29, 149)),
+        "violet-950" => Some(Color::rgb_u8(46, 16, 101)),
+        "purple-50" => Some(Color::rgb_u8(250, 245, 255)),
+        "purple-100" => Some(Color::rgb_u8(243, 232, 255)),
+        "purple-200" => Some(Color::rgb_u8(233, 213, 255)),
+        "purple-300" => Some(Color::rgb_u8(216, 180, 254)),
+        "purple-400" => Some(Color::rgb_u8(192, 132, 252)),
+        "purple-500" => Some(Color::rgb_u8(168, 85, 247)),
+        "purple-600" => Some(Color::rgb_u8(147, 51, 234)),
+        "purple-700" => Some(Color::rgb_u8(126, 34, 206)),
+        "purple-800" => Some(Color::rgb_u8(107, 33, 168)),
+        "purple-900" => Some(Color::rgb_u8(88, 28, 135)),
+        "purple-950" => Some(Color::rgb_u8(59, 7, 100)),
+        "fuchsia-50" => Some(Color::rgb_u8(253, 244, 255)),
+        "fuchsia-100" => Some(Color::rgb_u8(250, 232, 255)),
+        "fuchsia-200" => Some(Color::rgb_u8(245, 208, 254)),
+        "fuchsia-300" => Some(Color::rgb_u8(240, 171, 252)),
+        "fuchsia-400" => Some(Color::rgb_u8(232, 121, 249)),
+        "fuchsia-500" => Some(Color::rgb_u8(217, 70, 239)),
+        "fuchsia-600" => Some(Color::rgb_u8(192, 38, 211)),
+        "fuchsia-700" => Some(Color::rgb_u8(162, 28, 175)),
+        "fuchsia-800" => Some(Color::rgb_u8(134, 25, 143)),
+        "fuchsia-900" => Some(Color::rgb_u8(112, 26, 117)),
+        "fuchsia-950" => Some(Color::rgb_u8(74, 4, 78)),
+        "pink-50" => Some(Color::rgb_u8(253, 242, 248)),
+        "pink-100" => Some(Color::rgb_u8(252, 231, 243)),
+        "pink-200" => Some(Color::rgb_u8(251, 207, 232)),
+        "pink-300" => Some(Color::rgb_u8(249, 168, 212)),
+        "pink-400" => Some(Color::rgb_u8(244, 114, 182)),
+        "pink-500" => Some(Color::rgb_u8(236, 72, 153)),
+        "pink-600" => Some(Color::rgb_u8(219, 39, 119)),
+        "pink-700" => Some(Color::rgb_u8(190, 24, 93)),
+        "pink-800" => Some(Color::rgb_u8(157, 23, 77)),
+        "pink-900" => Some(Color::rgb_u8(131, 24, 67)),
+        "pink-950" => Some(Color::rgb_u8(80, 7, 36)),
+        "rose-50" => Some(Color::rgb_u8(255, 241, 242)),
+        "rose-100" => Some(Color::rgb_u8(255, 228, 230)),
+        "rose-200" => Some(Color::rgb_u8(254, 205, 211)),
+        "rose-300" => Some(Color::rgb_u8(253, 164, 175)),
+        "rose-400" => Some(Color::rgb_u8(251, 113, 133)),
+        "rose-500" => Some(Color::rgb_u8(244, 63, 94)),
+        "rose-600" => Some(Color::rgb_u8(225, 29, 72)),
+        "rose-700" => Some(Color::rgb_u8(190, 18, 60)),
+        "rose-800" => Some(Color::rgb_u8(159, 18, 57)),
+        "rose-900" => Some(Color::rgb_u8(136, 19, 55)),
+        "rose-950" => Some(Color::rgb_u8(76, 5, 25)),
+        _ => None,
     }
+}
 
-    if size.ends_with("px") {
-        return size[0..size.len() - 2].parse::<f32>().map(Val::Px).ok();
+/// Parses a CSS color in hex (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`), `rgb()`, `rgba()`,
+/// `hsl()` or `hsla()` notation. Arguments may be separated by commas or by `_`, which stands in
+/// for spaces in class names.
+fn parse_css_color(s: &str) -> Option<Color> {
+    if let Some(hex) = s.strip_prefix("#") {
+        return Color::hex(hex).ok();
     }
 
-    if size.contains('/') {
-        return parse_ratio(&size);
+    let (function, args) = s.strip_suffix(")")?.split_once("(")?;
+    let args = args
+        .split([',', '_', ' ', '/'])
+        .filter(|arg| !arg.is_empty())
+        .collect::<Vec<_>>();
+
+    let (channels, alpha) = match args.as_slice() {
+        [a, b, c] => ([*a, *b, *c], 1.0),
+        [a, b, c, alpha] => ([*a, *b, *c], parse_unit_interval(alpha)?),
+        _ => return None,
+    };
+
+    match function {
+        "rgb" | "rgba" => {
+            let [r, g, b] = channels.map(|channel| match channel.strip_suffix("%") {
+                Some(percent) => parse_number(percent).map(|v| v / 100.0),
+                None => parse_number(channel).map(|v| v / 255.0),
+            });
+            Some(Color::rgba(r?, g?, b?, alpha))
+        }
+        "hsl" | "hsla" => {
+            let [hue, saturation, lightness] = channels;
+            let hue = parse_number(hue.strip_suffix("deg").unwrap_or(hue))?;
+            Some(Color::hsla(
+                hue.rem_euclid(360.0),
+                parse_unit_interval(saturation)?,
+                parse_unit_interval(lightness)?,
+                alpha,
+            ))
+        }
+        _ => None,
     }
+}
 
-    None
+/// Parses a number, rejecting `inf` and `NaN`, which would otherwise reach the layout.
+fn parse_number(s: &str) -> Option<f32> {
+    s.parse::<f32>().ok().filter(|number| number.is_finite())
+}
+
+/// Parses either a percentage, `50%`, or a plain fraction, `0.5`, into `0.0..=1.0`.
+fn parse_unit_interval(s: &str) -> Option<f32> {
+    let value = match s.strip_suffix("%") {
+        Some(percent) => parse_number(percent)? / 100.0,
+        None => parse_number(s)?,
+    };
+    Some(value.clamp(0.0, 1.0))
 }
 
 fn parse_ratio(s: &str) -> Option<Val> {
     let (fst, snd) = s.split_once('/').unwrap();
     let numerator = fst.parse::<u32>().ok()?;
-    let denominator = snd.parse::<u32>().ok()?;
+    let denominator = snd
+        .parse::<u32>()
+        .ok()
+        .filter(|denominator| *denominator != 0)?;
     Some(Val::Percent(numerator as f32 / denominator as f32 * 100.0))
 }
+
+impl DefaultStyleSheet {
+    /// Parses a CSS length such as `12px`, `50%`, `1.5rem`, `100vh` or `10vmin`.
+    fn parse_size(&self, size: &str) -> Option<Val> {
+        if size == "0" {
+            return Some(Val::Px(0.0));
+        }
+
+        if let Some(number) = size.strip_suffix("%") {
+            return parse_number(number).map(Val::Percent);
+        }
+
+        if let Some(number) = size.strip_suffix("px") {
+            return parse_number(number).map(Val::Px);
+        }
+
+        if let Some(number) = size.strip_suffix("rem") {
+            return parse_number(number).map(|v| Val::Px(v * self.rem));
+        }
+
+        if let Some(number) = size.strip_suffix("vmin") {
+            return parse_number(number).map(Val::VMin);
+        }
+
+        if let Some(number) = size.strip_suffix("vmax") {
+            return parse_number(number).map(Val::VMax);
+        }
+
+        if let Some(number) = size.strip_suffix("vw") {
+            return parse_number(number).map(Val::Vw);
+        }
+
+        if let Some(number) = size.strip_suffix("vh") {
+            return parse_number(number).map(Val::Vh);
+        }
+
+        if size.contains('/') {
+            return parse_ratio(size);
+        }
+
+        None
+    }
+
+    /// Parses a bracketed arbitrary size such as `[3px]`.
+    fn parse_arbitrary_size(&self, value: &str) -> Option<Val> {
+        let size = value.strip_prefix("[")?.strip_suffix("]")?;
+        self.parse_size(size)
+    }
+
+    fn parse_spacing(&self, value: &str) -> Option<Val> {
+        if value == "px" {
+            return Some(Val::Px(1.0));
+        }
+
+        if value.starts_with("[") {
+            return self.parse_arbitrary_size(value);
+        }
+
+        let number = parse_number(value)?;
+        Some(Val::Px(number * self.rem / 4.0))
+    }
+
+    fn parse_margin(&self, value: &str) -> Option<Val> {
+        if value == "auto" {
+            return Some(Val::Auto);
+        }
+
+        self.parse_spacing(value)
+    }
+
+    /// Like [`Self::parse_spacing`], but also accepts keywords, viewport units and fractions
+    /// such as `1/2`.
+    fn parse_length(&self, value: &str) -> Option<Val> {
+        match value {
+            "full" => return Some(Val::Percent(100.0)),
+            // Bevy's layout has no intrinsic sizing keywords, `auto` sizes a node to its content.
+            "auto" | "fit" | "min" | "max" => return Some(Val::Auto),
+            "svw" | "lvw" | "dvw" => return Some(Val::Vw(100.0)),
+            "svh" | "lvh" | "dvh" => return Some(Val::Vh(100.0)),
+            _ => {}
+        }
+
+        if value.contains('/') && !value.starts_with("[") {
+            return parse_ratio(value);
+        }
+
+        self.parse_spacing(value)
+    }
+
+    /// Parses an arbitrary grid template such as `200px_1fr_auto` or `repeat(3,minmax(0,1fr))`,
+    /// where `_` stands in for the spaces a class name can't contain.
+    fn parse_grid_template(&self, template: &str) -> Option<Vec<RepeatedGridTrack>> {
+        split_top_level(template, '_')
+            .into_iter()
+            .map(|track| {
+                if track.starts_with("repeat(") && track.ends_with(")") {
+                    let args = split_top_level(&track["repeat(".len()..track.len() - 1], ',');
+                    let (repetition, tracks) = args.split_first()?;
+                    let repetition = match *repetition {
+                        "auto-fill" => GridTrackRepetition::AutoFill,
+                        "auto-fit" => GridTrackRepetition::AutoFit,
+                        count => GridTrackRepetition::Count(parse_grid_span(count)?),
+                    };
+                    let tracks = tracks
+                        .iter()
+                        .map(|track| self.parse_grid_track(track))
+                        .collect::<Option<Vec<_>>>()?;
+                    Some(RepeatedGridTrack::repeat_many(repetition, tracks))
+                } else {
+                    self.parse_grid_track(track).map(RepeatedGridTrack::from)
+                }
+            })
+            .collect()
+    }
+
+    fn parse_grid_track(&self, track: &str) -> Option<GridTrack> {
+        if track.starts_with("minmax(") && track.ends_with(")") {
+            let args = split_top_level(&track["minmax(".len()..track.len() - 1], ',');
+            let [min, max] = args.as_slice() else {
+                return None;
+            };
+            return Some(GridTrack::minmax(
+                self.parse_min_track_sizing(min)?,
+                self.parse_max_track_sizing(max)?,
+            ));
+        }
+
+        if track.starts_with("fit-content(") && track.ends_with(")") {
+            return match self.parse_size(&track["fit-content(".len()..track.len() - 1])? {
+                Val::Px(limit) => Some(GridTrack::fit_content_px(limit)),
+                Val::Percent(limit) => Some(GridTrack::fit_content_percent(limit)),
+                _ => None,
+            };
+        }
+
+        match track {
+            "auto" => Some(GridTrack::auto()),
+            "min-content" => Some(GridTrack::min_content()),
+            "max-content" => Some(GridTrack::max_content()),
+            fr if fr.ends_with("fr") => parse_number(&fr[0..fr.len() - 2]).map(GridTrack::fr),
+            size => match self.parse_size(size)? {
+                Val::Px(value) => Some(GridTrack::px(value)),
+                Val::Percent(value) => Some(GridTrack::percent(value)),
+                _ => None,
+            },
+        }
+    }
+
+    fn parse_min_track_sizing(&self, s: &str) -> Option<MinTrackSizingFunction> {
+        match s {
+            "auto" => Some(MinTrackSizingFunction::Auto),
+            "min-content" => Some(MinTrackSizingFunction::MinContent),
+            "max-content" => Some(MinTrackSizingFunction::MaxContent),
+            size => match self.parse_size(size)? {
+                Val::Px(value) => Some(MinTrackSizingFunction::Px(value)),
+                Val::Percent(value) => Some(MinTrackSizingFunction::Percent(value)),
+                _ => None,
+            },
+        }
+    }
+
+    fn parse_max_track_sizing(&self, s: &str) -> Option<MaxTrackSizingFunction> {
+        match s {
+            "auto" => Some(MaxTrackSizingFunction::Auto),
+            "min-content" => Some(MaxTrackSizingFunction::MinContent),
+            "max-content" => Some(MaxTrackSizingFunction::MaxContent),
+            fr if fr.ends_with("fr") => {
+                parse_number(&fr[0..fr.len() - 2]).map(MaxTrackSizingFunction::Fraction)
+            }
+            size => match self.parse_size(size)? {
+                Val::Px(value) => Some(MaxTrackSizingFunction::Px(value)),
+                Val::Percent(value) => Some(MaxTrackSizingFunction::Percent(value)),
+                _ => None,
+            },
+        }
+    }
+}
+
+fn parse_aspect_ratio(s: &str) -> Option<f32> {
+    match s.split_once('/') {
+        Some((width, height)) => {
+            let width = parse_number(width.trim())?;
+            let height = parse_number(height.trim())?;
+            (height != 0.0).then(|| width / height)
+        }
+        None => parse_number(s),
+    }
+}
+
+/// Parses `z-10`, `-z-10`, `z-[7]` and their `z-global-*` counterparts, which stack relative to
+/// the whole UI instead of the node's siblings.
+fn parse_z_index(name: &str) -> Option<ZIndex> {
+    let (negative, name) = match name.strip_prefix('-') {
+        Some(name) => (true, name),
+        None => (false, name),
+    };
+    let name = name.strip_prefix("z-")?;
+    let (global, value) = match name.strip_prefix("global-") {
+        Some(value) => (true, value),
+        None => (false, name),
+    };
+
+    let value = match value {
+        "auto" => 0,
+        "0" | "10" | "20" | "30" | "40" | "50" => value.parse::<i32>().ok()?,
+        arbitrary if arbitrary.starts_with("[") && arbitrary.ends_with("]") => {
+            arbitrary[1..arbitrary.len() - 1].parse::<i32>().ok()?
+        }
+        _ => return None,
+    };
+    let value = if negative { -value } else { value };
+
+    if global {
+        Some(ZIndex::Global(value))
+    } else {
+        Some(ZIndex::Local(value))
+    }
+}
+
+fn parse_grid_span(s: &str) -> Option<u16> {
+    unbracket(s).parse::<u16>().ok().filter(|span| *span > 0)
+}
+
+fn parse_grid_line(s: &str) -> Option<i16> {
+    unbracket(s).parse::<i16>().ok().filter(|line| *line != 0)
+}
+
+/// Strips the brackets off an arbitrary value like `[3]`, leaving other values as they are.
+fn unbracket(s: &str) -> &str {
+    s.strip_prefix("[")
+        .and_then(|s| s.strip_suffix("]"))
+        .unwrap_or(s)
+}
+
+fn clear_grid_start(placement: GridPlacement) -> GridPlacement {
+    let span = placement.get_span().unwrap_or(1);
+    match placement.get_end() {
+        Some(end) => GridPlacement::end_span(end, span),
+        None => GridPlacement::span(span),
+    }
+}
+
+fn clear_grid_end(placement: GridPlacement) -> GridPlacement {
+    let span = placement.get_span().unwrap_or(1);
+    match placement.get_start() {
+        Some(start) => GridPlacement::start_span(start, span),
+        None => GridPlacement::span(span),
+    }
+}
+
+/// Splits `s` on `separator`, ignoring separators nested inside parentheses.
+fn split_top_level(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PALETTE: [&str; 22] = [
+        "slate", "gray", "zinc", "neutral", "stone", "red", "orange", "amber", "yellow", "lime",
+        "green", "emerald", "teal", "cyan", "sky", "blue", "indigo", "violet", "purple", "fuchsia",
+        "pink", "rose",
+    ];
+
+    const SHADES: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+    fn assert_alpha(color: Option<Color>, alpha: f32) {
+        let color = color.expect("color should parse");
+        assert!(
+            (color.a() - alpha).abs() < 1e-6,
+            "expected alpha {alpha}, got {}",
+            color.a()
+        );
+    }
+
+    #[test]
+    fn every_palette_color_is_opaque_by_default() {
+        for family in PALETTE {
+            for shade in SHADES {
+                assert_alpha(parse_color(&format!("{family}-{shade}")), 1.0);
+            }
+        }
+        assert_alpha(parse_color("black"), 1.0);
+        assert_alpha(parse_color("white"), 1.0);
+    }
+
+    #[test]
+    fn opacity_modifier_is_a_percentage() {
+        for family in PALETTE {
+            for shade in SHADES {
+                assert_alpha(parse_color(&format!("{family}-{shade}/50")), 0.5);
+            }
+        }
+        assert_alpha(parse_color("black/0"), 0.0);
+        assert_alpha(parse_color("black/5"), 0.05);
+        assert_alpha(parse_color("black/50"), 0.5);
+        assert_alpha(parse_color("white/100"), 1.0);
+    }
+
+    #[test]
+    fn opacity_modifier_keeps_the_color() {
+        let color = parse_color("red-500/25").unwrap();
+        assert_eq!(color, Color::rgb_u8(239, 68, 68).with_a(0.25));
+    }
+
+    #[test]
+    fn arbitrary_opacity_modifier() {
+        assert_alpha(parse_color("black/[0.37]"), 0.37);
+        assert_alpha(parse_color("black/[37%]"), 0.37);
+        assert_alpha(parse_color("[#1e293b]/50"), 0.5);
+        assert_alpha(parse_color("[rgb(30,41,59)]/[0.25]"), 0.25);
+    }
+
+    #[test]
+    fn invalid_opacity_modifier() {
+        assert_eq!(parse_color("black/101"), None);
+        assert_eq!(parse_color("black/-1"), None);
+        assert_eq!(parse_color("black/half"), None);
+        assert_eq!(parse_color("black/"), None);
+        assert_eq!(parse_color("[#000]50"), None);
+    }
+
+    #[test]
+    fn opacity_modifier_applies_to_bg_text_and_border() {
+        let stylesheet = DefaultStyleSheet { rem: REM };
+        let styles = stylesheet
+            .get_styles("bg-black/50 text-white/20 border-red-500/75")
+            .unwrap()
+            .style;
+
+        assert_alpha(styles.background_color.map(|c| c.0), 0.5);
+        assert_alpha(styles.color, 0.2);
+        assert_alpha(styles.border_color.map(|c| c.0), 0.75);
+    }
+
+    #[test]
+    fn unknown_utility_warns() {
+        let stylesheet = DefaultStyleSheet { rem: REM };
+        let styles = stylesheet.get_styles("flex flexx").unwrap();
+
+        assert_eq!(
+            styles.warnings,
+            vec![Error::UnknownUtility("flexx".to_string())]
+        );
+    }
+
+    #[test]
+    fn conflicting_utilities_warn() {
+        let stylesheet = DefaultStyleSheet { rem: REM };
+        let styles = stylesheet.get_styles("p-4 px-2 p-2").unwrap();
+
+        assert_eq!(
+            styles.warnings,
+            vec![
+                Error::Conflict {
+                    class: "px-2".to_string(),
+                    previous: "p-4".to_string(),
+                },
+                Error::Conflict {
+                    class: "p-2".to_string(),
+                    previous: "p-4".to_string(),
+                },
+                Error::Conflict {
+                    class: "p-2".to_string(),
+                    previous: "px-2".to_string(),
+                },
+            ]
+        );
+        assert_eq!(styles.style.padding.top, Some(Val::Px(REM * 0.5)));
+    }
+
+    #[test]
+    fn overlapping_shorthands_warn() {
+        let stylesheet = DefaultStyleSheet { rem: REM };
+
+        for (classes, class, previous) in [
+            ("px-2 p-4", "p-4", "px-2"),
+            ("w-4 size-8", "size-8", "w-4"),
+            ("border-t-2 border-4", "border-4", "border-t-2"),
+            ("truncate overflow-visible", "overflow-visible", "truncate"),
+            ("gap-x-2 gap-4", "gap-4", "gap-x-2"),
+        ] {
+            let styles = stylesheet.get_styles(classes).unwrap();
+            assert_eq!(
+                styles.warnings,
+                vec![Error::Conflict {
+                    class: class.to_string(),
+                    previous: previous.to_string(),
+                }],
+                "{classes}"
+            );
+        }
+
+        let styles = stylesheet.get_styles("px-2 py-4 mt-2 size-8 col-start-2 col-span-3");
+        assert!(styles.unwrap().warnings.is_empty());
+    }
+
+    #[test]
+    fn only_named_and_arbitrary_font_families_are_accepted() {
+        let stylesheet = DefaultStyleSheet { rem: REM };
+        let styles = stylesheet.get_styles("font-mono font-[Fira_Sans]").unwrap();
+        assert_eq!(styles.style.font_family.as_deref(), Some("Fira Sans"));
+
+        let styles = stylesheet.get_styles("font-bolder font-semi-bold").unwrap();
+        assert_eq!(styles.style.font_family, None);
+        assert_eq!(
+            styles.warnings,
+            vec![
+                Error::UnknownUtility("font-bolder".to_string()),
+                Error::UnknownUtility("font-semi-bold".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_value_warns() {
+        let stylesheet = DefaultStyleSheet { rem: REM };
+        let styles = stylesheet
+            .get_styles("w-[12furlongs] bg-[#12] text-[nope]")
+            .unwrap();
+
+        assert_eq!(
+            styles.warnings,
+            vec![
+                Error::MalformedValue("w-[12furlongs]".to_string()),
+                Error::MalformedValue("bg-[#12]".to_string()),
+                Error::MalformedValue("text-[nope]".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_spans_keep_their_start_line_in_either_order() {
+        let stylesheet = DefaultStyleSheet { rem: REM };
+        let expected = GridPlacement::start_span(2, 3);
+
+        for classes in ["col-start-2 col-span-3", "col-span-3 col-start-2"] {
+            let styles = stylesheet.get_styles(classes).unwrap();
+            assert_eq!(styles.style.grid_column, Some(expected), "{classes}");
+        }
+        for classes in ["row-start-2 row-span-3", "row-span-3 row-start-2"] {
+            let styles = stylesheet.get_styles(classes).unwrap();
+            assert_eq!(styles.style.grid_row, Some(expected), "{classes}");
+        }
+    }
+
+    #[test]
+    fn bracketed_grid_and_flex_values() {
+        let stylesheet = DefaultStyleSheet { rem: REM };
+        let styles = stylesheet
+            .get_styles("col-span-[3] row-start-[2] grow-[2] shrink-[0.5]")
+            .unwrap();
+
+        assert!(styles.warnings.is_empty());
+        assert_eq!(styles.style.grid_column, Some(GridPlacement::span(3)));
+        assert_eq!(styles.style.grid_row, Some(GridPlacement::start(2)));
+        assert_eq!(styles.style.flex_grow, Some(2.0));
+        assert_eq!(styles.style.flex_shrink, Some(0.5));
+    }
+
+    #[test]
+    fn non_finite_values_are_malformed() {
+        let stylesheet = DefaultStyleSheet { rem: REM };
+        let styles = stylesheet
+            .get_styles("w-1/0 p-inf h-[NaNpx] grow-[inf]")
+            .unwrap();
+
+        assert_eq!(
+            styles.warnings,
+            vec![
+                Error::MalformedValue("w-1/0".to_string()),
+                Error::MalformedValue("p-inf".to_string()),
+                Error::MalformedValue("h-[NaNpx]".to_string()),
+                Error::MalformedValue("grow-[inf]".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsupported_variant_warns() {
+        let stylesheet = DefaultStyleSheet { rem: REM };
+        let styles = stylesheet.get_styles("hover:bg-red-500").unwrap();
+
+        assert_eq!(
+            styles.warnings,
+            vec![Error::UnsupportedVariant {
+                variant: "hover".to_string(),
+                class: "hover:bg-red-500".to_string(),
+            }]
+        );
+        assert!(styles.style.background_color.is_none());
+    }
+}